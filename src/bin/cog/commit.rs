@@ -5,6 +5,7 @@ use cocogitto::COMMITS_METADATA;
 use anyhow::{bail, Result};
 use conventional_commit_parser::commit::Separator;
 use itertools::Itertools;
+use lazy_static::lazy_static;
 
 pub fn commit_types() -> Vec<&'static str> {
     COMMITS_METADATA
@@ -13,6 +14,39 @@ pub fn commit_types() -> Vec<&'static str> {
         .collect()
 }
 
+lazy_static! {
+    static ref COMMIT_TYPES_HELP: String = {
+        let mut help = "COMMIT TYPES:\n".to_string();
+        for (commit_type, config) in COMMITS_METADATA
+            .iter()
+            .sorted_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()))
+        {
+            let description = config
+                .help_message
+                .as_deref()
+                .unwrap_or(&config.changelog_title);
+            let _ = writeln!(help, "    {:<12}{}", commit_type.as_ref(), description);
+            let details = [
+                ("message", &config.message_help),
+                ("--scope", &config.scope_help),
+                ("--body", &config.body_help),
+                ("example", &config.example),
+            ];
+            for (name, detail) in details {
+                if let Some(detail) = detail {
+                    let _ = writeln!(help, "    {:<12}{}: {}", "", name, detail);
+                }
+            }
+        }
+        help
+    };
+}
+
+/// Commit types description, used as `cog commit` after help
+pub fn commit_types_help() -> &'static str {
+    COMMIT_TYPES_HELP.as_str()
+}
+
 pub fn edit_message(
     typ: &str,
     message: &str,
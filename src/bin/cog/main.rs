@@ -1,19 +1,29 @@
 mod commit;
 
 use std::path::PathBuf;
+use std::process::exit;
 
-use cocogitto::conventional::changelog::template::{RemoteContext, Template};
+use cocogitto::conventional::changelog::parser::diff_sections;
+use cocogitto::conventional::changelog::template::{RemoteContext, Template, TemplateKind};
 use cocogitto::conventional::commit as conv_commit;
+use cocogitto::conventional::error::BumpError;
 use cocogitto::conventional::version::VersionIncrement;
+use cocogitto::doctor;
+use cocogitto::error::{sarif_log, CogCheckReport};
 use cocogitto::git::hook::HookKind;
 use cocogitto::git::revspec::RevspecPattern;
 use cocogitto::log::filter::{CommitFilter, CommitFilters};
+use cocogitto::log::order::LogOrder;
 use cocogitto::log::output::Output;
-use cocogitto::{CocoGitto, SETTINGS};
+use cocogitto::settings::{self, EmptyRelease, Preset, Settings};
+use cocogitto::{BumpOptions, CocoGitto, CONFIG_PATH, SETTINGS};
 
-use anyhow::{Context, Result};
-use clap::{AppSettings, ArgGroup, Args, CommandFactory, Parser, Subcommand};
+use anyhow::{ensure, Context, Result};
+use clap::{AppSettings, ArgEnum, ArgGroup, Args, CommandFactory, Parser, Subcommand};
 use clap_complete::Shell;
+use colored::Colorize;
+use log::{info, warn};
+use semver::Prerelease;
 
 fn hook_profiles() -> Vec<&'static str> {
     SETTINGS
@@ -23,6 +33,15 @@ fn hook_profiles() -> Vec<&'static str> {
         .collect()
 }
 
+fn preset_names() -> Vec<&'static str> {
+    Preset::ALL.iter().map(Preset::name).collect()
+}
+
+/// `cog check` exit code when non compliant commits were found
+const CHECK_VIOLATIONS_EXIT_CODE: i32 = 1;
+/// `cog check` exit code on any other error, e.g. an invalid config or repository
+const CHECK_ERROR_EXIT_CODE: i32 = 2;
+
 /// A command line tool for the conventional commits and semver specifications
 #[derive(Parser)]
 #[clap(global_setting = AppSettings::DeriveDisplayOrder)]
@@ -40,6 +59,11 @@ struct Cli {
     #[clap(long, short = 'q')]
     quiet: bool,
 
+    /// When to use colors: 'auto' follows the terminal and the NO_COLOR, CLICOLOR
+    /// and CLICOLOR_FORCE environment variables
+    #[clap(long, arg_enum, global = true, default_value = "auto")]
+    color: ColorChoice,
+
     #[clap(subcommand)]
     command: Command,
 }
@@ -47,6 +71,9 @@ struct Cli {
 #[derive(Subcommand)]
 enum Command {
     /// Verify all commit messages against the conventional commit specification
+    ///
+    /// Exits with 0 when all commits are compliant, 1 when non compliant commits were found
+    /// and 2 on any other error.
     Check {
         /// Check commit history, starting from the latest tag to HEAD
         #[clap(short = 'l', long)]
@@ -54,11 +81,33 @@ enum Command {
         /// Ignore merge commits messages
         #[clap(short, long)]
         ignore_merge_commits: bool,
+        /// Rewrite the commits with only whitespace issues (trailing or repeated spaces, missing
+        /// space after the commit type) before checking, other errors are still reported
+        #[clap(long)]
+        fix: bool,
+        /// Print the messages `--fix` would rewrite commits with, without touching history
+        #[clap(long, requires = "fix")]
+        dry_run: bool,
+        /// Skip the commits found compliant by previous runs, cached in `.git/.cog-cache`
+        #[clap(long)]
+        cache: bool,
+        /// Only check the commits of the current branch since it forked from the default branch,
+        /// the `default_branch` setting or the branch `origin/HEAD` points to
+        #[clap(long, conflicts_with_all = &["from-latest-tag", "fix"])]
+        pr_base: bool,
+        /// Report format, `sarif` prints a SARIF 2.1.0 log on stdout for code scanning tools
+        #[clap(long, arg_enum, default_value = "text")]
+        format: CheckFormat,
     },
 
     /// Create a new conventional commit
+    #[clap(after_help = commit::commit_types_help())]
     Commit(CommitArgs),
 
+    /// A commit type alias from the `commit_aliases` setting, e.g. `cog f` for `cog commit feat`
+    #[clap(external_subcommand)]
+    Alias(Vec<String>),
+
     /// Interactively rename invalid commit messages
     Edit {
         /// Edit non conventional commits, starting from the latest tag to HEAD
@@ -66,6 +115,32 @@ enum Command {
         from_latest_tag: bool,
     },
 
+    /// Rewrite the message of a commit into a conventional one, rebasing its descendants
+    Reword {
+        /// The commit to reword, HEAD or one of its ancestors
+        commit: String,
+
+        /// The new commit message, opens $EDITOR with the current message when omitted
+        #[clap(short, long)]
+        message: Option<String>,
+
+        /// Also rewrite a commit already pushed to a remote branch
+        #[clap(long)]
+        force: bool,
+    },
+
+    /// Replace the scope of the last commit, refusing if it was already pushed
+    AmendScope {
+        /// The new commit scope
+        scope: String,
+    },
+
+    /// Replace the type of the last commit, refusing if it was already pushed
+    AmendType {
+        /// The new commit type
+        commit_type: String,
+    },
+
     /// Like git log but for conventional commits
     Log {
         /// filter BREAKING CHANGE commits
@@ -87,6 +162,65 @@ enum Command {
         /// omit error on the commit log
         #[clap(short = 'e', long)]
         no_error: bool,
+
+        /// display full commit hashes
+        #[clap(long)]
+        full_hash: bool,
+
+        /// hide the commit scopes
+        #[clap(long)]
+        no_scope: bool,
+
+        /// only print the number of matching commits
+        #[clap(long, conflicts_with = "full-hash")]
+        count_only: bool,
+
+        /// only show commits modifying files under this path
+        #[clap(long)]
+        path: Option<PathBuf>,
+
+        /// show no parent before all of its children and avoid intermixing lines of history,
+        /// like `git log --topo-order`
+        #[clap(long)]
+        topo_order: bool,
+
+        /// show the oldest commits first
+        #[clap(long)]
+        reverse: bool,
+
+        /// output format: `terminal` is colored when printing to a terminal, `plain` never is,
+        /// `markdown` prints a list to paste in a pull request description and `json` a JSON array
+        #[clap(
+            long,
+            arg_enum,
+            default_value = "terminal",
+            conflicts_with = "count-only"
+        )]
+        format: LogFormat,
+
+        /// with `--format json`, print every release with its commits grouped by
+        /// changelog section instead, filters do not apply
+        #[clap(
+            long,
+            conflicts_with_all = &[
+                "typ",
+                "author",
+                "scope",
+                "breaking-change",
+                "no-error",
+                "path",
+                "topo-order",
+                "reverse"
+            ]
+        )]
+        all_tags: bool,
+    },
+
+    /// List the repository SemVer tags
+    Tags {
+        /// Output format
+        #[clap(long, arg_enum, default_value = "text")]
+        format: OutputFormat,
     },
 
     /// Verify a single commit message
@@ -98,7 +232,17 @@ enum Command {
         ignore_merge_commits: bool,
     },
 
+    /// Verify a commit message file before committing, defaults to .git/COMMIT_EDITMSG
+    DiffCheck {
+        /// Path to the commit message file
+        path: Option<PathBuf>,
+        /// Fix trailing whitespaces and missing space after the commit type, then verify
+        #[clap(long)]
+        fix: bool,
+    },
+
     /// Display a changelog for the given commit oid range
+    #[clap(group = ArgGroup::new("range").multiple(true))]
     Changelog {
         /// Generate the changelog from in the given spec range
         #[clap(conflicts_with = "at")]
@@ -108,12 +252,61 @@ enum Command {
         #[clap(short, long)]
         at: Option<String>,
 
+        /// With `--at`, start the release from this tag instead of the tag preceding it,
+        /// e.g. when backports make the automatic one irrelevant
+        #[clap(long, requires = "at")]
+        previous_tag: Option<String>,
+
+        /// Generate the changelog from this tag or commit, defaults to the latest tag
+        #[clap(long, group = "range", conflicts_with_all = &["pattern", "at"])]
+        from: Option<String>,
+
+        /// Generate the changelog from the latest release tag, this is the default
+        /// when `--to` is HEAD
+        #[clap(long, group = "range", conflicts_with_all = &["pattern", "at", "from"])]
+        from_latest_release: bool,
+
+        /// Only render the commits since the latest release tag, nothing when there are none
+        #[clap(
+            long,
+            conflicts_with_all = &["pattern", "at", "from", "from-latest-release", "to", "split-by-tag"]
+        )]
+        unreleased_only: bool,
+
+        /// Generate the changelog up to this tag or commit, defaults to HEAD
+        #[clap(long, group = "range", conflicts_with_all = &["pattern", "at"])]
+        to: Option<String>,
+
+        /// With `--from` or `--to`, render one section per release tag in the range
+        /// instead of a single section
+        #[clap(long, requires = "range", conflicts_with_all = &["pattern", "at"])]
+        split_by_tag: bool,
+
+        /// Print the sections of the existing changelog file documented between two versions,
+        /// without reading the git history
+        #[clap(
+            long,
+            number_of_values = 2,
+            value_names = &["FROM", "TO"],
+            conflicts_with_all = &["pattern", "at", "from", "to", "split-by-tag"]
+        )]
+        diff: Option<Vec<String>>,
+
         /// Generate the changelog with the given template.
-        /// Possible values are 'remote', 'full_hash', 'default' or the path to your template.  
+        /// Possible values are 'remote', 'full_hash', 'keepachangelog', 'default' or the path
+        /// to your template.
         /// If not specified cog will use cog.toml template config or fallback to 'default'.
         #[clap(name = "template", long, short)]
         template: Option<String>,
 
+        /// Render each release as a single list of commits, without commit type sections
+        #[clap(long, conflicts_with = "template")]
+        flat: bool,
+
+        /// Changelog format, `keepachangelog` is the same as `--template keepachangelog`
+        #[clap(long, arg_enum, conflicts_with_all = &["template", "flat"])]
+        format: Option<ChangelogFormat>,
+
         /// Url to use during template generation
         #[clap(name = "remote", long, short, requires_all(&["owner", "repository"]))]
         remote: Option<String>,
@@ -125,6 +318,35 @@ enum Command {
         /// Name of the repository used during template generation
         #[clap(name = "repository", long, requires_all(& ["owner", "remote"]))]
         repository: Option<String>,
+
+        /// Write the changelog to this file instead of stdout, with the configured header and
+        /// footer. With `--diff`, read the sections from this file.
+        #[clap(long)]
+        changelog_path: Option<PathBuf>,
+
+        /// With `--changelog-path`, replace the file if it already exists
+        #[clap(long, requires = "changelog-path")]
+        overwrite: bool,
+
+        /// With `--changelog-path`, also print the changelog written to the file
+        #[clap(long, requires = "changelog-path")]
+        tee: bool,
+
+        /// List breaking changes before the other commits of their section
+        #[clap(long)]
+        group_breaking_first: bool,
+
+        /// Only include commits modifying files under this path, e.g. a monorepo package
+        #[clap(long)]
+        path: Option<PathBuf>,
+
+        /// Fail when commits are left out of the changelog because they cannot be parsed
+        #[clap(long)]
+        strict: bool,
+
+        /// Package to generate the changelog of, selecting its version tags when `bump.tag_format` contains `{scope}`
+        #[clap(long)]
+        package: Option<String>,
     },
 
     /// Commit changelog from latest tag to HEAD and create new tag
@@ -158,9 +380,84 @@ enum Command {
         #[clap(short = 'H', long, possible_values = hook_profiles())]
         hook_profile: Option<String>,
 
+        /// Package to bump, selecting its version tags when `bump.tag_format` contains `{scope}`
+        #[clap(long)]
+        package: Option<String>,
+
         /// Dry-run : get the target version. No action taken
         #[clap(short, long)]
         dry_run: bool,
+
+        /// Dry-run output format, `json` also lists the released commits and changelog
+        #[clap(long, arg_enum, default_value = "text", requires = "dry-run")]
+        format: OutputFormat,
+
+        /// Only create the version tag, without generating the changelog
+        #[clap(long)]
+        no_changelog: bool,
+
+        /// Write the changelog to this file instead of the configured one
+        #[clap(long, conflicts_with = "no-changelog")]
+        changelog_path: Option<PathBuf>,
+
+        /// Amend the last commit with the version changes instead of creating a bump commit
+        #[clap(long)]
+        amend: bool,
+
+        /// Append the `bump.skip_ci` marker, `[skip ci]` by default, to the version commit
+        #[clap(long, conflicts_with = "amend")]
+        skip_ci: bool,
+
+        /// Bump even with uncommitted changes, they are included in the version commit
+        #[clap(long)]
+        allow_dirty: bool,
+
+        /// Release a patch version even without any `bump.release_commit_types` commit
+        #[clap(long, requires = "auto")]
+        force: bool,
+
+        /// Compute the version and the changelog from the commits after this ref instead of
+        /// the latest tag, e.g. when the latest tag is misplaced
+        #[clap(long)]
+        from_ref: Option<String>,
+
+        /// Version commit message instead of `bump.commit_message_template`, `{{version}}` is
+        /// replaced with the new version tag
+        #[clap(long, conflicts_with = "amend")]
+        message: Option<String>,
+
+        /// Finish a bump interrupted after its version commit was created, by tagging HEAD
+        #[clap(
+            long,
+            group = "bump-spec",
+            conflicts_with_all = &[
+                "pre",
+                "dry-run",
+                "no-changelog",
+                "changelog-path",
+                "amend",
+                "skip-ci",
+                "allow-dirty",
+                "from-ref",
+                "message"
+            ]
+        )]
+        retry: bool,
+    },
+
+    /// Print the version `cog bump --auto` would create. No action taken
+    NextVersion {
+        /// Set the pre-release version
+        #[clap(long)]
+        pre: Option<String>,
+
+        /// Package to get the next version of, selecting its version tags when `bump.tag_format` contains `{scope}`
+        #[clap(long)]
+        package: Option<String>,
+
+        /// Output format, `shields` prints a shields.io endpoint badge with the next version
+        #[clap(long, arg_enum, default_value = "text")]
+        format: NextVersionFormat,
     },
 
     /// Install cog config files
@@ -168,12 +465,27 @@ enum Command {
         /// path to init
         #[clap(default_value = ".")]
         path: PathBuf,
+
+        /// Write an opinionated config preset instead of the default config
+        #[clap(long, possible_values = preset_names())]
+        template: Option<Preset>,
+    },
+
+    /// Check the cog.toml config file and report all its problems
+    ValidateConfig {
+        /// Path to the config file, defaults to cog.toml
+        path: Option<PathBuf>,
     },
 
+    /// Check that the repository and its config are ready to use with cog
+    Doctor,
+
     /// Add git hooks to the repository
     InstallHook {
-        /// Type of hook to install
-        #[clap(possible_values = &["commit-msg", "pre-push", "all"])]
+        /// Type of hook to install, `prepare-commit-msg` prefills the message of a bare
+        /// `git commit` with a commented conventional commit template (it does not launch
+        /// an interactive builder), `all` installs `commit-msg` and `pre-push`
+        #[clap(possible_values = &["commit-msg", "pre-push", "prepare-commit-msg", "all"])]
         hook_type: String,
     },
 
@@ -185,6 +497,51 @@ enum Command {
     },
 }
 
+#[derive(ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum CheckFormat {
+    Text,
+    Sarif,
+}
+
+#[derive(ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum ChangelogFormat {
+    Keepachangelog,
+}
+
+#[derive(ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum NextVersionFormat {
+    Text,
+    Shields,
+}
+
+#[derive(ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum LogFormat {
+    Terminal,
+    Plain,
+    Markdown,
+    Json,
+}
+
+/// `cog <alias>` arguments, the aliased commit type being inserted before them
+#[derive(Parser)]
+struct AliasArgs {
+    #[clap(flatten)]
+    commit: CommitArgs,
+}
+
 #[derive(Args)]
 struct CommitArgs {
     /// Conventional commit type
@@ -194,9 +551,27 @@ struct CommitArgs {
     /// Commit description
     message: String,
 
+    /// Deprecated, use `--scope` instead
+    #[clap(
+        name = "legacy-scope",
+        value_name = "SCOPE",
+        hide = true,
+        conflicts_with = "scope"
+    )]
+    legacy_scope: Option<String>,
+
     /// Conventional commit scope
+    #[clap(long)]
     scope: Option<String>,
 
+    /// Commit body
+    #[clap(long, conflicts_with = "edit")]
+    body: Option<String>,
+
+    /// Commit footer, e.g. `Refs: #123`, can be repeated
+    #[clap(long, multiple_occurrences = true, conflicts_with = "edit")]
+    footer: Vec<String>,
+
     /// Create a BREAKING CHANGE commit
     #[clap(short = 'B', long)]
     breaking_change: bool,
@@ -205,15 +580,21 @@ struct CommitArgs {
     #[clap(short, long)]
     edit: bool,
 
-    /// Sign this commit
-    #[clap(short, long)]
+    /// Sign this commit, `-s` adds a `Signed-off-by` trailer instead
+    #[clap(short = 'S', long)]
     sign: bool,
+
+    /// Add a `Signed-off-by` trailer using the configured git user
+    #[clap(short, long)]
+    signoff: bool,
 }
 
 fn main() -> Result<()> {
+    check_commit_aliases()?;
     let cli = Cli::parse();
 
-    init_logs(cli.verbose, cli.quiet);
+    init_colors(cli.color);
+    init_logs(cli.verbose, cli.quiet, cli.color);
 
     match cli.command {
         Command::Bump {
@@ -224,9 +605,26 @@ fn main() -> Result<()> {
             patch,
             pre,
             hook_profile,
+            package,
             dry_run,
+            format,
+            no_changelog,
+            changelog_path,
+            amend,
+            skip_ci,
+            allow_dirty,
+            force,
+            from_ref,
+            message,
+            retry,
         } => {
             let mut cocogitto = CocoGitto::get()?;
+            cocogitto.select_package(package.as_deref())?;
+
+            if retry {
+                cocogitto.retry_bump(hook_profile.as_deref())?;
+                return Ok(());
+            }
 
             let increment = match version {
                 Some(version) => VersionIncrement::Manual(version),
@@ -237,7 +635,79 @@ fn main() -> Result<()> {
                 _ => unreachable!(),
             };
 
-            cocogitto.create_version(increment, pre.as_deref(), hook_profile.as_deref(), dry_run)?
+            let release = cocogitto.create_version(
+                increment,
+                BumpOptions {
+                    pre_release: pre.as_deref(),
+                    hooks_config: hook_profile.as_deref(),
+                    dry_run,
+                    no_changelog,
+                    changelog_path: changelog_path.as_deref(),
+                    amend,
+                    skip_ci,
+                    allow_dirty,
+                    force,
+                    from_ref: from_ref.as_deref(),
+                    commit_message: message.as_deref(),
+                },
+            );
+
+            let skip_empty_release = SETTINGS.bump.empty_release == EmptyRelease::Skip;
+            let release = match release {
+                Err(err) if skip_empty_release => match err.downcast_ref::<BumpError>() {
+                    Some(BumpError::NoReleasableChanges) => {
+                        info!(
+                            "No releasable changes since the latest version, nothing to release. \
+                            Use `cog bump --auto --force` to release anyway"
+                        );
+                        return Ok(());
+                    }
+                    Some(BumpError::NoCommitFound) => {
+                        info!(
+                            "No feature, bug fix or breaking change since the latest version, \
+                            nothing to release"
+                        );
+                        return Ok(());
+                    }
+                    _ => return Err(err),
+                },
+                release => release?,
+            };
+
+            if dry_run {
+                match format {
+                    OutputFormat::Json => {
+                        let summary = cocogitto.dry_run_summary(release, from_ref.as_deref())?;
+                        println!("{}", serde_json::to_string_pretty(&summary)?);
+                    }
+                    OutputFormat::Text => print!("{}", release.tag),
+                }
+            }
+        }
+        Command::NextVersion {
+            pre,
+            package,
+            format,
+        } => {
+            let mut cocogitto = CocoGitto::get()?;
+            cocogitto.select_package(package.as_deref())?;
+            let mut version = cocogitto.next_version(VersionIncrement::Auto)?;
+            if let Some(pre) = pre {
+                version.pre = Prerelease::new(&pre)?;
+            }
+
+            match format {
+                NextVersionFormat::Shields => {
+                    let badge = serde_json::json!({
+                        "schemaVersion": 1,
+                        "label": "next",
+                        "message": version.to_string(),
+                        "color": "blue",
+                    });
+                    println!("{}", badge);
+                }
+                NextVersionFormat::Text => println!("{}", version),
+            }
         }
         Command::Verify {
             message,
@@ -250,35 +720,90 @@ fn main() -> Result<()> {
 
             conv_commit::verify(author, &message, ignore_merge_commits)?;
         }
+        Command::DiffCheck { path, fix } => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.diff_check(path.as_deref(), fix)?;
+        }
         Command::Check {
             from_latest_tag,
             ignore_merge_commits,
+            fix,
+            dry_run,
+            cache,
+            pr_base,
+            format,
         } => {
-            let cocogitto = CocoGitto::get()?;
             let ignore_merge_commits = ignore_merge_commits || SETTINGS.ignore_merge_commits;
-            cocogitto.check(from_latest_tag, ignore_merge_commits)?;
+            let result = CocoGitto::get().and_then(|cocogitto| {
+                if fix {
+                    cocogitto.fix_commits(from_latest_tag, ignore_merge_commits, dry_run)?;
+                }
+                if pr_base {
+                    cocogitto.check_pull_request(ignore_merge_commits, cache)
+                } else {
+                    cocogitto.check(from_latest_tag, ignore_merge_commits, cache)
+                }
+            });
+
+            if format == CheckFormat::Sarif {
+                let sarif = match &result {
+                    Err(err) => err
+                        .downcast_ref::<CogCheckReport>()
+                        .map(CogCheckReport::to_sarif),
+                    Ok(()) => Some(sarif_log(&[])),
+                };
+
+                if let Some(sarif) = sarif {
+                    println!("{}", serde_json::to_string_pretty(&sarif)?);
+                }
+            }
+
+            if let Err(err) = result {
+                eprintln!("Error: {:?}", err);
+                if err.is::<CogCheckReport>() {
+                    exit(CHECK_VIOLATIONS_EXIT_CODE);
+                } else {
+                    exit(CHECK_ERROR_EXIT_CODE);
+                }
+            }
         }
         Command::Edit { from_latest_tag } => {
             let cocogitto = CocoGitto::get()?;
             cocogitto.check_and_edit(from_latest_tag)?;
         }
+        Command::Reword {
+            commit,
+            message,
+            force,
+        } => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.reword(&commit, message.as_deref(), force)?;
+        }
+        Command::AmendScope { scope } => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.amend_scope(&scope)?;
+        }
+        Command::AmendType { commit_type } => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.amend_type(&commit_type)?;
+        }
         Command::Log {
             breaking_change,
             typ,
             author,
             scope,
             no_error,
+            full_hash,
+            no_scope,
+            count_only,
+            path,
+            topo_order,
+            reverse,
+            format,
+            all_tags,
         } => {
             let cocogitto = CocoGitto::get()?;
 
-            let repo_tag_name = cocogitto.get_repo_tag_name();
-            let repo_tag_name = repo_tag_name.as_deref().unwrap_or("cog log");
-
-            let mut output = Output::builder()
-                .with_pager_from_env("PAGER")
-                .with_file_name(repo_tag_name)
-                .build()?;
-
             let mut filters = vec![];
             if let Some(commit_types) = typ {
                 filters.extend(
@@ -304,52 +829,240 @@ fn main() -> Result<()> {
                 filters.push(CommitFilter::NoError);
             }
 
+            if let Some(path) = path {
+                filters.push(CommitFilter::Path(path));
+            }
+
             let filters = CommitFilters(filters);
+            let order = LogOrder {
+                topological: topo_order,
+                reverse,
+            };
+            ensure!(
+                !all_tags || format == LogFormat::Json,
+                "`--all-tags` requires `--format json`"
+            );
+
+            if matches!(format, LogFormat::Plain | LogFormat::Markdown) {
+                colored::control::set_override(false);
+            }
 
-            let content = cocogitto.get_log(filters)?;
-            output
-                .handle()?
-                .write_all(content.as_bytes())
-                .context("failed to write log into the pager")?;
+            if all_tags {
+                cocogitto.write_release_history(std::io::stdout().lock())?;
+                println!();
+            } else if format == LogFormat::Json {
+                cocogitto.write_log_json(filters, order, std::io::stdout().lock())?;
+                println!();
+            } else if count_only {
+                println!("{}", cocogitto.count_log(filters)?);
+            } else {
+                let repo_tag_name = cocogitto.get_repo_tag_name();
+                let repo_tag_name = repo_tag_name.as_deref().unwrap_or("cog log");
+
+                let mut output = Output::builder()
+                    .with_pager_from_env("PAGER")
+                    .with_file_name(repo_tag_name)
+                    .build()?;
+
+                let content = if format == LogFormat::Markdown {
+                    cocogitto.get_markdown_log(filters, order, full_hash, !no_scope)?
+                } else {
+                    cocogitto.get_log(filters, order, full_hash, !no_scope)?
+                };
+                output
+                    .handle()?
+                    .write_all(content.as_bytes())
+                    .context("failed to write log into the pager")?;
+            }
+        }
+        Command::Tags { format } => {
+            let cocogitto = CocoGitto::get()?;
+            let tags = cocogitto.get_tags()?;
+
+            match format {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&tags)?),
+                OutputFormat::Text => {
+                    for tag in tags {
+                        let latest = if tag.latest { " (latest)" } else { "" };
+                        println!(
+                            "{} - {} - {} commits{}",
+                            tag.tag,
+                            &tag.id[0..7],
+                            tag.commits,
+                            latest
+                        );
+                    }
+                }
+            }
         }
         Command::Changelog {
             pattern,
             at,
+            previous_tag,
+            from,
+            from_latest_release,
+            unreleased_only,
+            to,
+            split_by_tag,
+            diff,
             template,
+            flat,
+            format,
             remote,
             owner,
             repository,
+            changelog_path,
+            overwrite,
+            tee,
+            group_breaking_first,
+            path,
+            strict,
+            package,
         } => {
-            let cocogitto = CocoGitto::get()?;
+            if let Some(versions) = diff {
+                let path = changelog_path
+                    .as_deref()
+                    .unwrap_or_else(|| settings::changelog_path());
+                let changelog = std::fs::read_to_string(path)
+                    .with_context(|| format!("failed to read {:?}", path))?;
+                print!("{}", diff_sections(&changelog, &versions[0], &versions[1])?);
+                return Ok(());
+            }
+
+            let mut cocogitto = CocoGitto::get()?;
+            cocogitto.select_package(package.as_deref())?;
 
             let context = RemoteContext::try_new(remote, repository, owner)
                 .or_else(|| SETTINGS.get_template_context());
-            let template = template.as_ref().or(SETTINGS.changelog.template.as_ref());
-            let template = if let Some(template) = template {
+            let template = if format == Some(ChangelogFormat::Keepachangelog) {
+                Template {
+                    context,
+                    kind: TemplateKind::KeepAChangelog,
+                }
+            } else if flat || (template.is_none() && SETTINGS.changelog.flat) {
+                Template {
+                    context,
+                    kind: TemplateKind::Flat,
+                }
+            } else if let Some(template) =
+                template.as_ref().or(SETTINGS.changelog.template.as_ref())
+            {
                 Template::from_arg(template, context)?
             } else {
                 Template::default()
             };
 
-            let pattern = pattern.as_deref().map(RevspecPattern::from);
+            let from = match from {
+                None if from_latest_release || unreleased_only || to.as_deref() == Some("HEAD") => {
+                    cocogitto.get_latest_release()
+                }
+                from => from,
+            };
 
-            let result = match at {
-                Some(at) => cocogitto.get_changelog_at_tag(&at, template)?,
-                None => {
-                    let changelog = cocogitto.get_changelog(pattern.unwrap_or_default(), true)?;
-                    changelog.into_markdown(template)?
+            let (pattern, with_child_releases) = match (at, pattern, from, to) {
+                (Some(at), _, _, _) => {
+                    let pattern = match previous_tag {
+                        Some(previous_tag) => cocogitto.release_range(&previous_tag, &at)?,
+                        None => format!("..{}", at),
+                    };
+                    (RevspecPattern::from(pattern.as_str()), false)
+                }
+                (None, Some(pattern), _, _) => (RevspecPattern::from(pattern.as_str()), true),
+                (None, None, None, None) => (RevspecPattern::default(), true),
+                (None, None, from, to) => {
+                    let from = from.unwrap_or_default();
+                    let to = to.unwrap_or_default();
+                    let pattern = format!("{}..{}", from, to);
+                    (RevspecPattern::from(pattern.as_str()), split_by_tag)
                 }
             };
-            println!("{}", result);
+
+            let mut changelog = cocogitto.get_changelog(pattern, with_child_releases)?;
+            if strict {
+                changelog.ensure_no_skipped_commits()?;
+            }
+            if let Some(path) = path {
+                cocogitto.retain_path(&mut changelog, &path)?;
+            }
+            if unreleased_only && changelog.commits.is_empty() {
+                info!("No unreleased changes since the latest release");
+                return Ok(());
+            }
+            if group_breaking_first {
+                changelog.sort_breaking_first();
+            }
+
+            match changelog_path {
+                Some(path) => {
+                    // The whole history is rendered, prepending it to an existing changelog
+                    // would duplicate its releases
+                    if path.exists() {
+                        ensure!(
+                            overwrite,
+                            "{:?} already exists, use `--overwrite` to replace it",
+                            path
+                        );
+                        std::fs::remove_file(&path)
+                            .with_context(|| format!("failed to remove {:?}", path))?;
+                    }
+
+                    changelog
+                        .write_to_file(&path, template)
+                        .with_context(|| format!("failed to write changelog to {:?}", path))?;
+                    if tee {
+                        print!("{}", std::fs::read_to_string(&path)?);
+                    }
+                }
+                None => println!("{}", changelog.into_markdown(template)?),
+            }
         }
-        Command::Init { path } => {
-            cocogitto::init(&path)?;
+        Command::Init { path, template } => {
+            cocogitto::init_with_preset(&path, template)?;
+        }
+        Command::ValidateConfig { path } => {
+            let path = path.unwrap_or_else(|| PathBuf::from(CONFIG_PATH));
+            let problems = Settings::validate_file(&path)
+                .with_context(|| format!("failed to read {:?}", path))?;
+
+            for problem in &problems {
+                eprintln!("{}", problem);
+            }
+
+            ensure!(
+                problems.is_empty(),
+                "{} problem(s) found in {:?}",
+                problems.len(),
+                path
+            );
+            println!("{:?} is valid", path);
+        }
+        Command::Doctor => {
+            let diagnostics = doctor::diagnose(".");
+
+            for diagnostic in &diagnostics {
+                let status = if diagnostic.passed {
+                    "✓".green()
+                } else if diagnostic.critical {
+                    "✗".red()
+                } else {
+                    "!".yellow()
+                };
+                println!("{} {}", status, diagnostic);
+            }
+
+            let failed = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.critical && !diagnostic.passed)
+                .count();
+
+            ensure!(failed == 0, "{} critical check(s) failed", failed);
         }
         Command::InstallHook { hook_type } => {
             let cocogitto = CocoGitto::get()?;
             match hook_type.as_str() {
                 "commit-msg" => cocogitto.install_hook(HookKind::PrepareCommit)?,
                 "pre-push" => cocogitto.install_hook(HookKind::PrePush)?,
+                "prepare-commit-msg" => cocogitto.install_hook(HookKind::PrepareCommitMsg)?,
                 "all" => cocogitto.install_hook(HookKind::All)?,
                 _ => unreachable!(),
             }
@@ -357,35 +1070,112 @@ fn main() -> Result<()> {
         Command::GenerateCompletions { shell } => {
             clap_complete::generate(shell, &mut Cli::command(), "cog", &mut std::io::stdout());
         }
-        Command::Commit(CommitArgs {
-            typ,
-            message,
-            scope,
-            breaking_change,
-            edit,
-            sign,
-        }) => {
-            let cocogitto = CocoGitto::get()?;
-            let (body, footer, breaking) = if edit {
-                commit::edit_message(&typ, &message, scope.as_deref(), breaking_change)?
-            } else {
-                (None, None, breaking_change)
+        Command::Commit(args) => run_commit(args)?,
+        Command::Alias(args) => {
+            let (alias, args) = args
+                .split_first()
+                .expect("external subcommands have a name");
+            let commit_type = match SETTINGS.commit_aliases.get(alias) {
+                Some(commit_type) => commit_type,
+                None => {
+                    // Parse again without aliases for clap to report the unknown subcommand
+                    // along with its suggestions
+                    Cli::command()
+                        .allow_external_subcommands(false)
+                        .get_matches();
+                    unreachable!("`{}` is not a cog subcommand", alias)
+                }
             };
 
-            cocogitto.conventional_commit(&typ, scope, message, body, footer, breaking, sign)?;
+            let args = AliasArgs::parse_from(
+                [format!("cog {}", alias), commit_type.clone()]
+                    .into_iter()
+                    .chain(args.iter().cloned()),
+            );
+            run_commit(args.commit)?;
         }
     }
 
     Ok(())
 }
 
-fn init_logs(verbose: i8, quiet: bool) {
+fn run_commit(args: CommitArgs) -> Result<()> {
+    let CommitArgs {
+        typ,
+        message,
+        legacy_scope,
+        scope,
+        body,
+        footer,
+        breaking_change,
+        edit,
+        sign,
+        signoff,
+    } = args;
+
+    let deprecated_scope = legacy_scope.is_some();
+    let scope = scope.or(legacy_scope);
+
+    let cocogitto = CocoGitto::get()?;
+    let (body, footer, breaking) = if edit {
+        commit::edit_message(&typ, &message, scope.as_deref(), breaking_change)?
+    } else {
+        let footer = if footer.is_empty() {
+            None
+        } else {
+            Some(footer.join("\n"))
+        };
+        (body, footer, breaking_change)
+    };
+
+    cocogitto.conventional_commit(&typ, scope, message, body, footer, breaking, sign, signoff)?;
+
+    // Warned once committed, so that errors are reported the same with either scope argument
+    if deprecated_scope {
+        warn!("The positional scope argument is deprecated, use `--scope` instead");
+    }
+
+    Ok(())
+}
+
+// Commit aliases are dispatched as external subcommands and must not shadow a builtin one
+fn check_commit_aliases() -> Result<()> {
+    let cli = Cli::command();
+    for alias in SETTINGS.commit_aliases.keys() {
+        ensure!(
+            alias != "help" && cli.find_subcommand(alias).is_none(),
+            "commit alias `{}` collides with the `cog {}` subcommand",
+            alias,
+            alias
+        );
+    }
+
+    Ok(())
+}
+
+fn init_colors(color: ColorChoice) {
+    match color {
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+        // `colored` already follows the terminal and the color environment variables
+        ColorChoice::Auto => {}
+    }
+}
+
+fn init_logs(verbose: i8, quiet: bool, color: ColorChoice) {
     let verbosity = if verbose == 0 { 2 } else { verbose - 1 };
+    let color = match color {
+        ColorChoice::Auto => stderrlog::ColorChoice::Auto,
+        ColorChoice::Always => stderrlog::ColorChoice::Always,
+        ColorChoice::Never => stderrlog::ColorChoice::Never,
+    };
+
     stderrlog::new()
         .module(module_path!())
         .modules(vec!["cocogitto"])
         .quiet(quiet)
         .verbosity(verbosity as usize)
+        .color(color)
         .show_level(false)
         .show_module_names(false)
         .init()
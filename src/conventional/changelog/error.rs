@@ -1,3 +1,4 @@
+use crate::conventional::error::ConventionalCommitError;
 use serde::de::StdError;
 use std::fmt::{Debug, Display, Formatter};
 use std::io;
@@ -9,6 +10,9 @@ pub enum ChangelogError {
     TeraError(tera::Error),
     WriteError(io::Error),
     SeparatorNotFound(PathBuf),
+    UnknownCommitType(Box<ConventionalCommitError>),
+    VersionNotFound(String),
+    SkippedCommits(Vec<String>),
 }
 
 impl Display for ChangelogError {
@@ -28,6 +32,19 @@ impl Display for ChangelogError {
                 "cannot find default separator '- - -' in {}",
                 path.as_path().display()
             ),
+            ChangelogError::UnknownCommitType(err) => {
+                writeln!(f, "unknown commit type in changelog range: \n\t{}", err)
+            }
+            ChangelogError::VersionNotFound(version) => {
+                writeln!(f, "version {} not found in changelog", version)
+            }
+            ChangelogError::SkippedCommits(commits) => {
+                writeln!(f, "{} commit(s) left out of the changelog:", commits.len())?;
+                for commit in commits {
+                    writeln!(f, "\t{}", commit)?;
+                }
+                Ok(())
+            }
         }
     }
 }
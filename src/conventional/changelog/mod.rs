@@ -3,10 +3,13 @@ use crate::conventional::changelog::renderer::Renderer;
 
 use crate::conventional::changelog::error::ChangelogError;
 use crate::conventional::changelog::template::Template;
+use crate::settings::LineEnding;
+use crate::SETTINGS;
 use std::fs;
 use std::path::Path;
 
 pub mod error;
+pub mod parser;
 pub(crate) mod release;
 pub(crate) mod renderer;
 pub(crate) mod serde;
@@ -27,16 +30,37 @@ impl Release<'_> {
         renderer.render(self)
     }
 
+    /// Prepend this release to the changelog file, returning the rendered release section.
     pub fn write_to_file<S: AsRef<Path>>(
         self,
         path: S,
         template: Template,
-    ) -> Result<(), ChangelogError> {
+    ) -> Result<String, ChangelogError> {
         let renderer = Renderer::try_new(template)?;
         let changelog = renderer.render(self)?;
 
-        let mut changelog_content = fs::read_to_string(path.as_ref())
+        let existing_content = fs::read_to_string(path.as_ref())
             .unwrap_or_else(|_| [DEFAULT_HEADER, DEFAULT_FOOTER].join(""));
+        let crlf = match SETTINGS.changelog.line_ending {
+            LineEnding::Auto => is_mostly_crlf(&existing_content),
+            LineEnding::Lf => false,
+            LineEnding::Crlf => true,
+        };
+        let mut changelog_content = existing_content.replace("\r\n", "\n");
+
+        if let Some(header) = SETTINGS.changelog.header.as_deref() {
+            replace_header(&mut changelog_content, header);
+        }
+
+        if let Some(footer) = SETTINGS.changelog.footer.as_deref() {
+            replace_footer(&mut changelog_content, footer);
+        }
+
+        if SETTINGS.changelog.latest_only {
+            remove_releases(&mut changelog_content);
+        } else {
+            remove_unreleased(&mut changelog_content);
+        }
 
         let separator_idx = changelog_content.find(CHANGELOG_SEPARATOR);
 
@@ -47,9 +71,15 @@ impl Release<'_> {
                 idx + CHANGELOG_SEPARATOR.len() + 1 + changelog.len(),
                 "\n- - -\n",
             );
+            if let Some(parent) = path.as_ref().parent() {
+                fs::create_dir_all(parent)?;
+            }
+            if crlf {
+                changelog_content = changelog_content.replace('\n', "\r\n");
+            }
             fs::write(path.as_ref(), changelog_content)?;
 
-            Ok(())
+            Ok(changelog)
         } else {
             Err(ChangelogError::SeparatorNotFound(
                 path.as_ref().to_path_buf(),
@@ -57,3 +87,66 @@ impl Release<'_> {
         }
     }
 }
+
+// Everything before the first separator is the changelog header,
+// replace it with the configured one so it is never duplicated.
+fn replace_header(changelog_content: &mut String, header: &str) {
+    if let Some(idx) = changelog_content.find(CHANGELOG_SEPARATOR) {
+        changelog_content.replace_range(..idx, &format!("{}\n\n", header.trim_end()));
+    }
+}
+
+// Drop all the release sections, each one being inserted as `\n<release>\n- - -\n`
+// after the header separator.
+fn remove_releases(changelog_content: &mut String) {
+    let first = changelog_content.find(CHANGELOG_SEPARATOR);
+    let last = changelog_content.rfind(CHANGELOG_SEPARATOR);
+    if let (Some(first), Some(last)) = (first, last) {
+        if first != last {
+            let releases_end = last + CHANGELOG_SEPARATOR.len() + 1;
+            let releases_end = releases_end.min(changelog_content.len());
+            changelog_content.replace_range(first + CHANGELOG_SEPARATOR.len()..releases_end, "");
+        }
+    }
+}
+
+// A full changelog generated before the bump may start with an `Unreleased` section,
+// the release being written supersedes it.
+fn remove_unreleased(changelog_content: &mut String) {
+    let section = changelog_content
+        .find(CHANGELOG_SEPARATOR)
+        .map(|first| first + CHANGELOG_SEPARATOR.len())
+        .and_then(|start| {
+            changelog_content[start..]
+                .find(CHANGELOG_SEPARATOR)
+                .map(|len| (start, start + len + CHANGELOG_SEPARATOR.len()))
+        });
+
+    if let Some((start, end)) = section {
+        let is_unreleased = changelog_content[start..end]
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with("<a id="))
+            .is_some_and(|title| title.starts_with("## Unreleased"));
+
+        if is_unreleased {
+            let end = (end + 1).min(changelog_content.len());
+            changelog_content.replace_range(start..end, "");
+        }
+    }
+}
+
+// Everything after the last separator is the changelog footer.
+fn replace_footer(changelog_content: &mut String, footer: &str) {
+    if let Some(idx) = changelog_content.rfind(CHANGELOG_SEPARATOR) {
+        let footer_start = idx + CHANGELOG_SEPARATOR.len();
+        changelog_content.replace_range(footer_start.., &format!("\n{}\n", footer.trim_end()));
+    }
+}
+
+// Whether most of the lines end with `\r\n` rather than a lone `\n`.
+fn is_mostly_crlf(content: &str) -> bool {
+    let crlf = content.matches("\r\n").count();
+    let lf = content.matches('\n').count() - crlf;
+    crlf > lf
+}
@@ -0,0 +1,282 @@
+use conventional_commit_parser::commit::CommitType;
+
+use crate::conventional::changelog::error::ChangelogError;
+use crate::conventional::changelog::CHANGELOG_SEPARATOR;
+use crate::{COMMITS_METADATA, SETTINGS};
+
+/// A release section of an existing changelog file, from its `## <version>` title
+/// to the next separator.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChangelogSection<'a> {
+    pub version: &'a str,
+    pub content: &'a str,
+}
+
+/// Split a changelog file into its release sections, newest first as written in the file.
+pub fn parse_sections(changelog: &str) -> Vec<ChangelogSection<'_>> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, &str)> = None;
+    let mut offset = 0;
+
+    for line in changelog.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if let Some(title) = trimmed.strip_prefix("## ") {
+            if let Some((start, version)) = current.take() {
+                sections.push(section(changelog, start, offset, version));
+            }
+            current = Some((offset, title_version(title)));
+        } else if trimmed == CHANGELOG_SEPARATOR {
+            if let Some((start, version)) = current.take() {
+                sections.push(section(changelog, start, offset, version));
+            }
+        }
+
+        offset += line.len();
+    }
+
+    if let Some((start, version)) = current {
+        sections.push(section(changelog, start, offset, version));
+    }
+
+    sections
+}
+
+/// Render the sections documented after the oldest of `from` and `to` up to and including
+/// the newest one, i.e. what the newest release adds to the oldest one.
+pub fn diff_sections(changelog: &str, from: &str, to: &str) -> Result<String, ChangelogError> {
+    let sections = parse_sections(changelog);
+    let position = |version: &str| {
+        sections
+            .iter()
+            .position(|section| is_same_version(section.version, version))
+            .ok_or_else(|| ChangelogError::VersionNotFound(version.to_string()))
+    };
+
+    let from = position(from)?;
+    let to = position(to)?;
+    // Sections are written newest first
+    let (newest, oldest) = if to <= from { (to, from) } else { (from, to) };
+
+    let diff = sections[newest..oldest]
+        .iter()
+        .map(|section| format!("{}\n", section.content))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Ok(diff)
+}
+
+/// The commit type of a changelog section title such as `Bug Fixes` or `#### Bug Fixes`,
+/// reversing the configured `changelog_title` mapping. Titles are compared case-insensitively
+/// since templates may capitalize them.
+pub fn commit_type_from_title(title: &str) -> Option<CommitType> {
+    let title = title.trim_start_matches('#').trim().to_lowercase();
+
+    COMMITS_METADATA
+        .iter()
+        .find(|(_, config)| config.changelog_title.to_lowercase() == title)
+        .map(|(commit_type, _)| commit_type.clone())
+}
+
+fn section<'a>(
+    changelog: &'a str,
+    start: usize,
+    end: usize,
+    version: &'a str,
+) -> ChangelogSection<'a> {
+    ChangelogSection {
+        version,
+        content: changelog[start..end].trim_end(),
+    }
+}
+
+// The version of a `1.0.0 - 2022-01-01` or `[1.0.0](<compare url>) - 2022-01-01` title.
+fn title_version(title: &str) -> &str {
+    let title = title.trim();
+    match title.strip_prefix('[') {
+        Some(linked) => linked.split(']').next().unwrap_or(linked),
+        None => title.split_whitespace().next().unwrap_or(title),
+    }
+}
+
+fn is_same_version(section_version: &str, version: &str) -> bool {
+    let without_prefix = |version: &'_ str| match SETTINGS.tag_prefix.as_deref() {
+        Some(prefix) => version.strip_prefix(prefix).unwrap_or(version).to_string(),
+        None => version.to_string(),
+    };
+
+    section_version == version || without_prefix(section_version) == without_prefix(version)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
+    use indoc::indoc;
+    use speculoos::prelude::*;
+
+    use crate::conventional::changelog::parser::{
+        commit_type_from_title, diff_sections, parse_sections,
+    };
+    use crate::conventional::changelog::release::ChangelogCommit;
+    use crate::conventional::commit::Commit;
+
+    const CHANGELOG: &str = indoc!(
+        "# Changelog
+        All notable changes to this project will be documented in this file.
+
+        - - -
+        ## [1.2.0](https://github.com/o/r/compare/1.1.0..1.2.0) - 2022-03-01
+        #### Features
+        - feature two - (bbbbbbb) - Tom
+        - - -
+
+        ## 1.1.0 - 2022-02-01
+        #### Bug Fixes
+        - a fix - (aaaaaaa) - Tom
+        - - -
+
+        ## 1.0.0 - 2022-01-01
+        #### Features
+        - feature one - (0000000) - Tom
+        - - -
+
+        Changelog generated by [cocogitto](https://github.com/cocogitto/cocogitto).
+        "
+    );
+
+    #[test]
+    fn should_parse_changelog_sections() {
+        // Act
+        let sections = parse_sections(CHANGELOG);
+
+        // Assert
+        let versions: Vec<&str> = sections.iter().map(|section| section.version).collect();
+        assert_that!(versions).is_equal_to(vec!["1.2.0", "1.1.0", "1.0.0"]);
+        assert_that!(sections[1].content)
+            .is_equal_to("## 1.1.0 - 2022-02-01\n#### Bug Fixes\n- a fix - (aaaaaaa) - Tom");
+    }
+
+    #[test]
+    fn should_diff_two_versions() {
+        // Act
+        let diff = diff_sections(CHANGELOG, "1.0.0", "1.2.0");
+
+        // Assert
+        assert_that!(diff).is_ok().is_equal_to(
+            indoc!(
+                "## [1.2.0](https://github.com/o/r/compare/1.1.0..1.2.0) - 2022-03-01
+                #### Features
+                - feature two - (bbbbbbb) - Tom
+
+                ## 1.1.0 - 2022-02-01
+                #### Bug Fixes
+                - a fix - (aaaaaaa) - Tom
+                "
+            )
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn should_diff_two_versions_in_any_order() {
+        // Act
+        let diff = diff_sections(CHANGELOG, "1.2.0", "1.1.0");
+
+        // Assert
+        assert_that!(diff).is_ok().is_equal_to(
+            indoc!(
+                "## [1.2.0](https://github.com/o/r/compare/1.1.0..1.2.0) - 2022-03-01
+                #### Features
+                - feature two - (bbbbbbb) - Tom
+                "
+            )
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn should_fail_to_diff_unknown_version() {
+        // Act
+        let diff = diff_sections(CHANGELOG, "0.1.0", "1.2.0");
+
+        // Assert
+        assert_that!(diff).is_err();
+    }
+
+    #[test]
+    fn should_round_trip_built_in_titles() {
+        let commit_types = [
+            CommitType::Feature,
+            CommitType::BugFix,
+            CommitType::Chore,
+            CommitType::Revert,
+            CommitType::Performances,
+            CommitType::Documentation,
+            CommitType::Style,
+            CommitType::Refactor,
+            CommitType::Test,
+            CommitType::Build,
+            CommitType::Ci,
+        ];
+
+        for commit_type in commit_types {
+            // Arrange
+            let commit = ChangelogCommit::from(Commit {
+                oid: "1234567890".to_string(),
+                message: ConventionalCommit {
+                    commit_type: commit_type.clone(),
+                    scope: None,
+                    summary: "placeholder".to_string(),
+                    body: None,
+                    footers: vec![],
+                    is_breaking_change: false,
+                },
+                author: "Tom".to_string(),
+                author_email: None,
+                date: Utc::now().naive_utc(),
+                gitmoji: None,
+                ticket: None,
+                pull_request: None,
+            });
+            let title = commit.changelog_title();
+
+            // Act
+            let parsed = commit_type_from_title(&title);
+
+            // Assert
+            assert_that!(parsed).is_some().is_equal_to(commit_type);
+        }
+    }
+
+    #[test]
+    fn should_parse_markdown_heading_title_ignoring_case() {
+        // Act
+        let parsed = commit_type_from_title("#### Build System");
+
+        // Assert
+        assert_that!(parsed)
+            .is_some()
+            .is_equal_to(CommitType::Build);
+    }
+
+    #[test]
+    fn should_parse_custom_type_title() {
+        // Act
+        let parsed = commit_type_from_title("This is the markdown title for `ex` commit type");
+
+        // Assert
+        assert_that!(parsed)
+            .is_some()
+            .is_equal_to(CommitType::Custom("ex".to_string()));
+    }
+
+    #[test]
+    fn should_not_parse_unknown_title() {
+        // Act
+        let parsed = commit_type_from_title("Unrelated Notes");
+
+        // Assert
+        assert_that!(parsed).is_none();
+    }
+}
@@ -1,15 +1,29 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+
 use chrono::{NaiveDateTime, Utc};
 use conventional_commit_parser::commit::Footer;
 use serde::Serialize;
 
-use crate::conventional::commit::Commit;
+use crate::conventional::changelog::error::ChangelogError;
+use crate::conventional::commit::{is_skipped_commit, Commit};
+use crate::conventional::error::ConventionalCommitError;
 use crate::git::oid::OidOf;
 use crate::git::revspec::CommitRange;
 use crate::settings;
+use crate::settings::{AuthorDisplay, ContributorsOrder, UnknownTypePolicy, WithinSectionSort};
+use crate::{COMMITS_METADATA, SETTINGS};
 use colored::Colorize;
 use git2::Oid;
 use log::warn;
 
+const THANKS_TRAILERS: [&str; 2] = ["Co-authored-by", "Reviewed-by"];
+const COAUTHOR_TRAILER: &str = "Co-authored-by";
+const CLOSING_KEYWORDS: [&str; 9] = [
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+const CHANGELOG_TRAILER: &str = "Changelog";
+
 #[derive(Debug, Serialize)]
 pub struct Release<'a> {
     pub version: OidOf,
@@ -17,6 +31,9 @@ pub struct Release<'a> {
     pub date: NaiveDateTime,
     pub commits: Vec<ChangelogCommit<'a>>,
     pub previous: Option<Box<Release<'a>>>,
+    /// Commits left out of the release because they could not be parsed
+    #[serde(skip)]
+    pub skipped: Vec<ConventionalCommitError>,
 }
 
 impl Release<'_> {
@@ -42,57 +59,386 @@ impl Release<'_> {
         }
     }
 
+    /// Fail when commits of this release, or of the previous ones, were left out because they
+    /// could not be parsed, e.g. for `cog changelog --strict`
+    pub fn ensure_no_skipped_commits(&self) -> Result<(), ChangelogError> {
+        let mut skipped = vec![];
+        let mut release = Some(self);
+        while let Some(current) = release {
+            skipped.extend(current.skipped.iter().map(|err| {
+                let oid = err.oid().unwrap_or("");
+                let oid = oid.get(0..7).unwrap_or(oid);
+                format!("{}: {}", oid, err.description())
+            }));
+            release = current.previous.as_deref();
+        }
+
+        if skipped.is_empty() {
+            Ok(())
+        } else {
+            Err(ChangelogError::SkippedCommits(skipped))
+        }
+    }
+
+    /// Order the commits of this release and the previous ones, commits of the same
+    /// section keep this relative order once grouped by the template.
+    pub fn sort_within_sections(&mut self, order: WithinSectionSort) {
+        match order {
+            WithinSectionSort::Date => self
+                .commits
+                .sort_by_key(|commit| Reverse(commit.commit.date)),
+            WithinSectionSort::Scope => self
+                .commits
+                .sort_by(|a, b| a.commit.message.scope.cmp(&b.commit.message.scope)),
+            WithinSectionSort::Alpha => self
+                .commits
+                .sort_by_key(|commit| commit.commit.message.summary.to_lowercase()),
+        }
+
+        if let Some(previous) = &mut self.previous {
+            previous.sort_within_sections(order);
+        }
+    }
+
+    /// Move breaking changes before the other commits of this release and the previous ones,
+    /// keeping the commits order otherwise.
+    pub fn sort_breaking_first(&mut self) {
+        self.commits
+            .sort_by_key(|commit| !commit.commit.message.is_breaking_change);
+
+        if let Some(previous) = &mut self.previous {
+            previous.sort_breaking_first();
+        }
+    }
+
     pub fn contains_oid(&self, oid: &Oid) -> bool {
         self.commits
             .iter()
             .any(|commit| commit.commit.oid == oid.to_string())
     }
+
+    /// Authors of this release commits, deduplicated on their email if known, then on their
+    /// username if any, or their git signature otherwise. With `count_coauthors`, the
+    /// `Co-authored-by` trailers of each commit are credited as well.
+    pub fn contributors(
+        &self,
+        order: ContributorsOrder,
+        count_coauthors: bool,
+    ) -> Vec<Contributor<'_>> {
+        let mut contributors: Vec<Contributor> = vec![];
+
+        for commit in &self.commits {
+            let mut authors = vec![Contributor {
+                username: commit.author_username,
+                signature: commit.commit.author.as_str(),
+                email: commit.commit.author_email.as_deref(),
+                commits: 1,
+            }];
+            if count_coauthors {
+                for coauthor in commit.coauthors() {
+                    if !authors
+                        .iter()
+                        .any(|author| author.is_same_author(&coauthor))
+                    {
+                        authors.push(coauthor);
+                    }
+                }
+            }
+
+            for author in authors {
+                match contributors
+                    .iter_mut()
+                    .find(|contributor| contributor.is_same_author(&author))
+                {
+                    Some(contributor) => {
+                        contributor.commits += 1;
+                        contributor.username = contributor.username.or(author.username);
+                        contributor.email = contributor.email.or(author.email);
+                    }
+                    None => contributors.push(author),
+                }
+            }
+        }
+
+        contributors.sort_by_cached_key(|contributor| contributor.name().to_lowercase());
+        if order == ContributorsOrder::Commits {
+            contributors.sort_by_key(|contributor| Reverse(contributor.commits));
+        }
+
+        contributors
+    }
+
+    /// Names from the `Co-authored-by` and `Reviewed-by` trailers of this release commits,
+    /// without duplicates.
+    pub fn thanks(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = vec![];
+        for name in self.commits.iter().flat_map(ChangelogCommit::thanks) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+    }
+
+    /// Html anchor id of this release section, e.g. `1-2-0` for version `1.2.0`
+    /// or `unreleased` when the release is not tagged.
+    pub fn anchor(&self) -> String {
+        let version = match &self.version {
+            OidOf::Tag(tag) => tag
+                .to_version()
+                .map(|version| version.to_string())
+                .unwrap_or_else(|_| tag.to_string()),
+            OidOf::Head(_) | OidOf::Other(_) => return "unreleased".to_string(),
+        };
+
+        version
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Contributor<'a> {
+    pub username: Option<&'a str>,
+    pub signature: &'a str,
+    pub email: Option<&'a str>,
+    pub commits: usize,
+}
+
+impl Contributor<'_> {
+    pub fn name(&self) -> &str {
+        self.username.unwrap_or(self.signature)
+    }
+
+    // Authors are the same person when their emails match or their usernames match. Without
+    // usernames, their signatures are compared unless both emails are known.
+    fn is_same_author(&self, other: &Contributor) -> bool {
+        if let (Some(email), Some(other)) = (self.email, other.email) {
+            if email.eq_ignore_ascii_case(other) {
+                return true;
+            }
+        }
+
+        match (self.username, other.username) {
+            (Some(username), Some(other)) => username == other,
+            (None, None) => {
+                (self.email.is_none() || other.email.is_none()) && self.signature == other.signature
+            }
+            _ => false,
+        }
+    }
 }
 
-impl<'a> From<CommitRange<'a>> for Release<'a> {
-    fn from(commit_range: CommitRange<'a>) -> Self {
+impl<'a> TryFrom<CommitRange<'a>> for Release<'a> {
+    type Error = ChangelogError;
+
+    fn try_from(commit_range: CommitRange<'a>) -> Result<Self, Self::Error> {
         let mut commits = vec![];
+        let mut skipped = vec![];
 
         for commit in commit_range.commits {
-            // Ignore merge commits
-            if let Some(message) = commit.message() {
-                if message.starts_with("Merge") {
-                    continue;
-                }
+            if is_excluded_author(&commit) {
+                continue;
+            }
+
+            // Ignore version commits
+            if SETTINGS
+                .bump
+                .is_version_commit(commit.message().unwrap_or(""))
+            {
+                continue;
+            }
+
+            if is_skipped_commit(&commit) {
+                continue;
             }
 
-            match Commit::from_git_commit(&commit) {
-                Ok(commit) => commits.push(ChangelogCommit::from(commit)),
-                Err(err) => {
-                    let err = err.to_string().red();
-                    warn!("{}", err);
+            // Merge commits are left out, unless they carry a pull request conventional message
+            let parsed = match Commit::from_released_commit(&commit) {
+                Some(parsed) => parsed,
+                None => continue,
+            };
+
+            match parsed {
+                Ok(commit) => {
+                    commits.extend(with_changelog_footer(commit).map(ChangelogCommit::from))
                 }
+                Err(err) => match (*err, &SETTINGS.changelog.unknown_type_policy) {
+                    (
+                        ConventionalCommitError::CommitTypeNotAllowed { .. },
+                        UnknownTypePolicy::Section(_),
+                    ) => match Commit::from_git_commit_with_unknown_type(&commit) {
+                        Ok(commit) => {
+                            commits.extend(with_changelog_footer(commit).map(ChangelogCommit::from))
+                        }
+                        Err(err) => skip(&mut skipped, *err),
+                    },
+                    (
+                        err @ ConventionalCommitError::CommitTypeNotAllowed { .. },
+                        UnknownTypePolicy::Error,
+                    ) => {
+                        return Err(ChangelogError::UnknownCommitType(Box::new(err)));
+                    }
+                    (err, _) => skip(&mut skipped, err),
+                },
             };
         }
 
-        Release {
+        let mut release = Release {
             version: commit_range.to,
             from: commit_range.from,
             date: Utc::now().naive_utc(),
             commits,
             previous: None,
+            skipped,
+        };
+
+        if let Some(order) = SETTINGS.changelog.within_section_sort {
+            release.sort_within_sections(order);
+        }
+
+        if SETTINGS.changelog.breaking_first {
+            release.sort_breaking_first();
         }
+
+        Ok(release)
     }
 }
 
+// Warn about a commit left out of the changelog, keeping its error for strict mode
+fn skip(skipped: &mut Vec<ConventionalCommitError>, err: ConventionalCommitError) {
+    warn!("{}", err.to_string().red());
+    skipped.push(err);
+}
+
 #[derive(Debug)]
 pub struct ChangelogCommit<'a> {
     pub author_username: Option<&'a str>,
     pub commit: Commit,
+    /// Diff stats against the first parent, only computed with `changelog.commit_stats`
+    pub stats: Option<CommitStats>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, Eq, PartialEq)]
+pub struct CommitStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl ChangelogCommit<'_> {
+    /// Names from the commit `Co-authored-by` and `Reviewed-by` trailers
+    pub fn thanks(&self) -> Vec<&str> {
+        self.commit
+            .message
+            .footers
+            .iter()
+            .filter(|footer| {
+                THANKS_TRAILERS
+                    .iter()
+                    .any(|trailer| footer.token.eq_ignore_ascii_case(trailer))
+            })
+            .map(|footer| match footer.content.split_once('<') {
+                Some((name, _email)) => name.trim(),
+                None => footer.content.trim(),
+            })
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// The commit author git signature rendered as `display`
+    pub fn signature(&self, display: AuthorDisplay) -> Cow<'_, str> {
+        let name = &self.commit.author;
+        match (display, &self.commit.author_email) {
+            (AuthorDisplay::Email, Some(email)) => Cow::Borrowed(email),
+            (AuthorDisplay::NameEmail, Some(email)) => Cow::Owned(format!("{} <{}>", name, email)),
+            _ => Cow::Borrowed(name),
+        }
+    }
+
+    /// Authors of the commit `Co-authored-by` trailers, credited with this commit
+    pub fn coauthors(&self) -> Vec<Contributor<'_>> {
+        self.commit
+            .message
+            .footers
+            .iter()
+            .filter(|footer| footer.token.eq_ignore_ascii_case(COAUTHOR_TRAILER))
+            .map(|footer| match footer.content.split_once('<') {
+                Some((name, email)) => (name.trim(), Some(email.trim().trim_end_matches('>'))),
+                None => (footer.content.trim(), None),
+            })
+            .filter(|(name, _email)| !name.is_empty())
+            .map(|(name, email)| Contributor {
+                username: settings::commit_username(name, email),
+                signature: name,
+                email,
+                commits: 1,
+            })
+            .collect()
+    }
+
+    /// Issue numbers from the commit closing footers, e.g. `Closes: #1, #2` or `Fixes #3`,
+    /// without duplicates.
+    pub fn closed_issues(&self) -> Vec<u64> {
+        let mut issues = vec![];
+        let closing_footers = self.commit.message.footers.iter().filter(|footer| {
+            CLOSING_KEYWORDS
+                .iter()
+                .any(|keyword| footer.token.eq_ignore_ascii_case(keyword))
+        });
+
+        for footer in closing_footers {
+            // `Fixes #3, #4` is parsed as the `Fixes` token, a `#` separator and `3, #4`
+            let references = footer
+                .content
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .map(|reference| reference.trim_start_matches('#'))
+                .filter_map(|reference| reference.parse::<u64>().ok());
+
+            for issue in references {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Title of the changelog section of this commit type
+    pub(crate) fn changelog_title(&self) -> String {
+        COMMITS_METADATA
+            .iter()
+            .find(|(commit_type, _config)| *commit_type == &self.commit.message.commit_type)
+            .map(|meta| meta.1.changelog_title.clone())
+            .unwrap_or_else(|| match &SETTINGS.changelog.unknown_type_policy {
+                UnknownTypePolicy::Section(title) => title.clone(),
+                _ => self.commit.message.commit_type.to_string(),
+            })
+    }
+
+    /// The commit id displayed in changelogs, abbreviated unless `full_hash` is enabled
+    pub(crate) fn shorthand(&self) -> &str {
+        if SETTINGS.changelog.full_hash {
+            &self.commit.oid
+        } else {
+            self.commit.oid.get(0..7).unwrap_or(&self.commit.oid)
+        }
+    }
 }
 
 impl From<Commit> for ChangelogCommit<'_> {
     fn from(commit: Commit) -> Self {
-        let author_username = settings::commit_username(&commit.author);
+        let author_username =
+            settings::commit_username(&commit.author, commit.author_email.as_deref());
 
         ChangelogCommit {
             author_username,
             commit,
+            stats: None,
         }
     }
 }
@@ -112,6 +458,44 @@ impl<'a> From<&'a Footer> for ChangelogFooter<'a> {
     }
 }
 
+// Whether the commit author is listed in `changelog.exclude_authors`, by name, email
+// or `Name <email>` signature.
+fn is_excluded_author(commit: &git2::Commit) -> bool {
+    let exclude_authors = &SETTINGS.changelog.exclude_authors;
+    if exclude_authors.is_empty() {
+        return false;
+    }
+
+    let author = commit.author();
+    let name = author.name().unwrap_or_default();
+    let email = author.email().unwrap_or_default();
+    let signature = format!("{} <{}>", name, email);
+
+    exclude_authors
+        .iter()
+        .any(|excluded| excluded == name || excluded == email || *excluded == signature)
+}
+
+// Apply the `Changelog:` footer of a commit: `skip` leaves it out of the changelog
+// and any other text replaces its summary.
+fn with_changelog_footer(mut commit: Commit) -> Option<Commit> {
+    let entry = commit
+        .message
+        .footers
+        .iter()
+        .find(|footer| footer.token.eq_ignore_ascii_case(CHANGELOG_TRAILER))
+        .map(|footer| footer.content.trim().to_string());
+
+    match entry {
+        Some(entry) if entry.eq_ignore_ascii_case("skip") => None,
+        Some(entry) if !entry.is_empty() => {
+            commit.message.summary = entry;
+            Some(commit)
+        }
+        _ => Some(commit),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use anyhow::Result;
@@ -121,12 +505,13 @@ mod test {
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    use crate::conventional::changelog::release::{ChangelogCommit, Release};
+    use crate::conventional::changelog::release::{ChangelogCommit, Contributor, Release};
     use crate::conventional::changelog::renderer::Renderer;
     use crate::conventional::changelog::template::{RemoteContext, Template, TemplateKind};
     use crate::conventional::commit::Commit;
     use crate::git::oid::OidOf;
     use crate::git::tag::Tag;
+    use crate::settings::{AuthorDisplay, ContributorsOrder};
 
     #[test]
     fn should_render_default_template() -> Result<()> {
@@ -183,6 +568,64 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn should_render_keepachangelog_template() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let renderer = Renderer::try_new(Template {
+            context: None,
+            kind: TemplateKind::KeepAChangelog,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0] - 2015-09-05
+                ### Added
+                - awesome feature - (17f7e23)
+                - **(parser)** implement the changelog generator - (17f7e23)
+
+                ### Fixed
+                - **(parser)** fix parser implementation - (17f7e23)
+
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_flat_template() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let renderer = Renderer::try_new(Template {
+            context: None,
+            kind: TemplateKind::Flat,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_render_github_template() -> Result<()> {
         // Arrange
@@ -216,6 +659,221 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn should_list_contributors_by_commits() {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[1].commit.author = "Jane Doe".to_string();
+
+        // Act
+        let contributors = release.contributors(ContributorsOrder::Commits, false);
+
+        // Assert
+        assert_eq!(
+            contributors,
+            vec![
+                Contributor {
+                    username: Some("oknozor"),
+                    signature: "Paul Delafosse",
+                    email: None,
+                    commits: 2,
+                },
+                Contributor {
+                    username: None,
+                    signature: "Jane Doe",
+                    email: None,
+                    commits: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_dedupe_contributors_by_email() {
+        // Arrange
+        let mut release = Release::fixture();
+        for commit in &mut release.commits {
+            commit.commit.author_email = Some("paul@example.org".to_string());
+        }
+
+        // Act
+        let contributors = release.contributors(ContributorsOrder::Commits, false);
+
+        // Assert
+        assert_eq!(
+            contributors,
+            vec![Contributor {
+                username: Some("oknozor"),
+                signature: "Paul Delafosse",
+                email: Some("paul@example.org"),
+                commits: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn should_list_contributors_by_name() {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[1].commit.author = "Jane Doe".to_string();
+
+        // Act
+        let contributors = release.contributors(ContributorsOrder::Name, false);
+
+        // Assert
+        let names: Vec<&str> = contributors.iter().map(Contributor::name).collect();
+        assert_eq!(names, vec!["Jane Doe", "oknozor"]);
+    }
+
+    #[test]
+    fn should_count_coauthors_as_contributors() {
+        // Arrange
+        let mut release = Release::fixture();
+        for commit in &mut release.commits {
+            commit.commit.author_email = Some("paul@example.org".to_string());
+        }
+        release.commits[0].commit.message = conventional_commit_parser::parse(
+            "fix: fix parser implementation\n\nCo-authored-by: Jane Doe <jane@example.org>\nCo-authored-by: Paul Delafosse <paul@example.org>",
+        )
+        .unwrap();
+
+        // Act
+        let contributors = release.contributors(ContributorsOrder::Commits, true);
+
+        // Assert
+        assert_eq!(
+            contributors,
+            vec![
+                Contributor {
+                    username: Some("oknozor"),
+                    signature: "Paul Delafosse",
+                    email: Some("paul@example.org"),
+                    commits: 3,
+                },
+                Contributor {
+                    username: None,
+                    signature: "Jane Doe",
+                    email: Some("jane@example.org"),
+                    commits: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_display_author_signature() {
+        // Arrange
+        let mut commit = Release::fixture().commits.remove(0);
+        commit.commit.author_email = Some("paul@example.org".to_string());
+
+        // Act
+        let name = commit.signature(AuthorDisplay::Name);
+        let email = commit.signature(AuthorDisplay::Email);
+        let name_email = commit.signature(AuthorDisplay::NameEmail);
+
+        // Assert
+        assert_eq!(name, "Paul Delafosse");
+        assert_eq!(email, "paul@example.org");
+        assert_eq!(name_email, "Paul Delafosse <paul@example.org>");
+    }
+
+    #[test]
+    fn should_display_author_name_without_email() {
+        // Arrange
+        let commit = Release::fixture().commits.remove(0);
+
+        // Act
+        let email = commit.signature(AuthorDisplay::Email);
+        let name_email = commit.signature(AuthorDisplay::NameEmail);
+
+        // Assert
+        assert_eq!(email, "Paul Delafosse");
+        assert_eq!(name_email, "Paul Delafosse");
+    }
+
+    #[test]
+    fn should_generate_anchor_from_version() {
+        // Arrange
+        let mut release = Release::fixture();
+        release.version = OidOf::Tag(Tag::new("1.2.0-rc.1", None).unwrap());
+
+        // Act
+        let anchor = release.anchor();
+
+        // Assert
+        assert_eq!(anchor, "1-2-0-rc-1");
+    }
+
+    #[test]
+    fn should_generate_unreleased_anchor() {
+        // Arrange
+        let mut release = Release::fixture();
+        release.version =
+            OidOf::Head(Oid::from_str("9bb5facac5724bc81385fdd740fedbb49056da00").unwrap());
+
+        // Act
+        let anchor = release.anchor();
+
+        // Assert
+        assert_eq!(anchor, "unreleased");
+    }
+
+    fn commit_with_message(message: &str) -> ChangelogCommit<'static> {
+        let mut commit = Release::fixture().commits.remove(0);
+        commit.commit.message = conventional_commit_parser::parse(message).unwrap();
+        commit
+    }
+
+    #[test]
+    fn should_get_closed_issues_from_comma_separated_footer() {
+        // Arrange
+        let commit = commit_with_message("fix: a bug\n\nCloses: #1, #2");
+
+        // Act
+        let issues = commit.closed_issues();
+
+        // Assert
+        assert_eq!(issues, vec![1, 2]);
+    }
+
+    #[test]
+    fn should_get_closed_issues_from_hash_separated_footers() {
+        // Arrange
+        let commit = commit_with_message("fix: a bug\n\nFixes #3 #4\nresolves #5");
+
+        // Act
+        let issues = commit.closed_issues();
+
+        // Assert
+        assert_eq!(issues, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn should_get_closed_issues_from_multiple_keywords_without_duplicates() {
+        // Arrange
+        let commit = commit_with_message(
+            "fix: a bug\n\nCloses: #1\nFixes #1, #7\nRefs: #8\nResolved: 9 and #10",
+        );
+
+        // Act
+        let issues = commit.closed_issues();
+
+        // Assert
+        assert_eq!(issues, vec![1, 7, 9, 10]);
+    }
+
+    #[test]
+    fn should_get_no_closed_issues_without_closing_footer() {
+        // Arrange
+        let commit = commit_with_message("fix: closes #1\n\nRefs: #1");
+
+        // Act
+        let issues = commit.closed_issues();
+
+        // Assert
+        assert_eq!(issues, Vec::<u64>::new());
+    }
+
     impl Release<'_> {
         pub fn fixture() -> Release<'static> {
             let date =
@@ -255,8 +913,13 @@ mod test {
                                 is_breaking_change: false,
                             },
                             author: paul_delafosse.to_string(),
+                            author_email: None,
                             date,
+                            gitmoji: None,
+                            ticket: None,
+                            pull_request: None,
                         },
+                        stats: None,
                     },
                     ChangelogCommit {
                         author_username: None,
@@ -275,8 +938,13 @@ mod test {
                                 is_breaking_change: false,
                             },
                             author: paul_delafosse.to_string(),
+                            author_email: None,
                             date,
+                            gitmoji: None,
+                            ticket: None,
+                            pull_request: None,
                         },
+                        stats: None,
                     },
                     ChangelogCommit {
                         author_username: Some("oknozor"),
@@ -295,11 +963,17 @@ mod test {
                                 is_breaking_change: false,
                             },
                             author: "James Delleck".to_string(),
+                            author_email: None,
                             date,
+                            gitmoji: None,
+                            ticket: None,
+                            pull_request: None,
                         },
+                        stats: None,
                     },
                 ],
                 previous: None,
+                skipped: vec![],
             }
         }
     }
@@ -2,8 +2,10 @@ use std::collections::HashMap;
 
 use tera::{get_json_pointer, to_value, try_get_value, Context, Tera, Value};
 
-use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::release::{ChangelogCommit, Release};
 use crate::conventional::changelog::template::{RemoteContext, Template};
+use crate::settings::KEEPACHANGELOG_CATEGORIES;
+use crate::{COMMITS_METADATA, SETTINGS};
 
 #[derive(Debug)]
 pub struct Renderer {
@@ -41,8 +43,14 @@ impl Renderer {
 
         Ok(release)
     }
-    fn render_release(&self, version: &Release) -> Result<String, tera::Error> {
+    pub(crate) fn render_release(&self, version: &Release) -> Result<String, tera::Error> {
         let mut template_context = Context::from_serialize(version)?;
+        template_context.insert("sections", &Self::sections(version));
+        template_context.insert(
+            "no_changes_placeholder",
+            &SETTINGS.changelog.no_changes_placeholder,
+        );
+        template_context.insert("keepachangelog_categories", &KEEPACHANGELOG_CATEGORIES);
         let context = self
             .template
             .context
@@ -53,8 +61,85 @@ impl Renderer {
             template_context.extend(context);
         }
 
-        self.tera
-            .render(self.template.kind.name(), &template_context)
+        let mut release = self
+            .tera
+            .render(self.template.kind.name(), &template_context)?;
+
+        if SETTINGS.changelog.anchors {
+            release.insert_str(0, &format!("<a id=\"{}\"></a>\n", version.anchor()));
+        }
+
+        if SETTINGS.changelog.contributors {
+            release.push_str(&self.render_contributors(version));
+        }
+
+        if SETTINGS.changelog.thanks {
+            release.push_str(&Self::render_thanks(version));
+        }
+
+        Ok(release)
+    }
+
+    // Changelog titles of the sections rendered for this release in alphabetical order, as
+    // the templates used to group commits, including the commit types without any commit
+    // when `show_empty_sections` is set
+    fn sections(version: &Release) -> Vec<String> {
+        let mut sections: Vec<String> = version
+            .commits
+            .iter()
+            .map(ChangelogCommit::changelog_title)
+            .collect();
+
+        // Several commit types may share a section with `titles` or `section_merge`
+        if SETTINGS.changelog.show_empty_sections {
+            sections.extend(
+                COMMITS_METADATA
+                    .values()
+                    .map(|config| config.changelog_title.clone()),
+            );
+        }
+
+        sections.sort_unstable();
+        sections.dedup();
+        sections
+    }
+
+    fn render_contributors(&self, version: &Release) -> String {
+        let contributors = version.contributors(
+            SETTINGS.changelog.contributors_order,
+            SETTINGS.changelog.count_coauthors,
+        );
+        if contributors.is_empty() {
+            return String::new();
+        }
+
+        let mut block = "#### Contributors\n".to_string();
+        for contributor in contributors {
+            let name = match (contributor.username, &self.template.context) {
+                (Some(username), Some(context)) => {
+                    format!("[@{}]({}/{})", username, context.platform_url(), username)
+                }
+                (Some(username), None) => format!("@{}", username),
+                (None, _) => contributor.signature.to_string(),
+            };
+
+            let plural = if contributor.commits == 1 { "" } else { "s" };
+            block.push_str(&format!(
+                "- {} ({} commit{})\n",
+                name, contributor.commits, plural
+            ));
+        }
+
+        block
+    }
+
+    fn render_thanks(version: &Release) -> String {
+        let thanks = version.thanks();
+        if thanks.is_empty() {
+            return String::new();
+        }
+
+        format!("With thanks to {}\n", thanks.join(", "))
     }
 
     // From git-cliff: https://github.com/orhun/git-cliff/blob/main/git-cliff-core/src/template.rs
@@ -1,10 +1,13 @@
+use std::borrow::Cow;
+
+use itertools::Itertools;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 
 use crate::conventional::changelog::release::{ChangelogCommit, ChangelogFooter};
 use crate::git::oid::OidOf;
 use crate::git::tag::Tag;
-use crate::COMMITS_METADATA;
+use crate::SETTINGS;
 
 impl Serialize for Tag {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -20,7 +23,7 @@ impl Serialize for ChangelogCommit<'_> {
     where
         S: Serializer,
     {
-        let mut commit = serializer.serialize_struct("Commit", 10)?;
+        let mut commit = serializer.serialize_struct("Commit", 18)?;
 
         let footers = &self
             .commit
@@ -30,26 +33,90 @@ impl Serialize for ChangelogCommit<'_> {
             .map(ChangelogFooter::from)
             .collect::<Vec<ChangelogFooter>>();
 
-        let commit_type = &COMMITS_METADATA
-            .iter()
-            .find(|(commit_type, _config)| *commit_type == &self.commit.message.commit_type)
-            .map(|meta| meta.1.changelog_title.clone())
-            .unwrap_or_else(|| self.commit.message.commit_type.to_string());
-
         commit.serialize_field("id", &self.commit.oid)?;
+        commit.serialize_field("shorthand", self.shorthand())?;
         commit.serialize_field("author", &self.author_username)?;
-        commit.serialize_field("signature", &self.commit.author)?;
-        commit.serialize_field("type", commit_type)?;
+        commit.serialize_field(
+            "signature",
+            &self.signature(SETTINGS.changelog.author_display),
+        )?;
+        commit.serialize_field("type", &self.changelog_title())?;
+        commit.serialize_field(
+            "category",
+            &SETTINGS.keepachangelog_category(&self.commit.message.commit_type),
+        )?;
         commit.serialize_field("date", &self.commit.date)?;
         commit.serialize_field("scope", &self.commit.message.scope)?;
-        commit.serialize_field("summary", &self.commit.message.summary)?;
+        let closed_issues = self.closed_issues();
+        let summary = match closed_issues.as_slice() {
+            issues if SETTINGS.changelog.closes_note && !issues.is_empty() => {
+                let issues = issues.iter().map(|issue| format!("#{}", issue)).join(", ");
+                Cow::Owned(format!(
+                    "{} (Closes {})",
+                    self.commit.message.summary, issues
+                ))
+            }
+            _ => Cow::Borrowed(&self.commit.message.summary),
+        };
+        match SETTINGS.get_issue_url_template() {
+            Some(url_template) => commit
+                .serialize_field("summary", &link_issue_references(&summary, &url_template))?,
+            None => commit.serialize_field("summary", &summary)?,
+        };
         commit.serialize_field("body", &self.commit.message.body)?;
         commit.serialize_field("breaking_change", &self.commit.message.is_breaking_change)?;
+        commit.serialize_field("thanks", &self.thanks())?;
+        commit.serialize_field("closed_issues", &closed_issues)?;
         commit.serialize_field("footer", footers)?;
+        commit.serialize_field("gitmoji", &self.commit.gitmoji)?;
+        commit.serialize_field("ticket", &self.commit.ticket)?;
+        commit.serialize_field("pull_request", &self.commit.pull_request)?;
+        commit.serialize_field("stats", &self.stats)?;
         commit.end()
     }
 }
 
+// Replace `#123` and `GH-123` issue references with markdown links, `{{id}}` in
+// `url_template` being replaced with the issue number.
+fn link_issue_references(text: &str, url_template: &str) -> String {
+    let mut linked = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = rest.chars().next() {
+        let at_word_boundary = !matches!(previous, Some(p) if p.is_alphanumeric() || p == '[');
+        let prefix_len = if rest.starts_with('#') {
+            1
+        } else if rest.starts_with("GH-") {
+            3
+        } else {
+            0
+        };
+
+        if at_word_boundary && prefix_len > 0 {
+            let id_len = rest[prefix_len..]
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .count();
+
+            if id_len > 0 {
+                let (reference, remaining) = rest.split_at(prefix_len + id_len);
+                let url = url_template.replace("{{id}}", &reference[prefix_len..]);
+                linked.push_str(&format!("[{}]({})", reference, url));
+                previous = reference.chars().last();
+                rest = remaining;
+                continue;
+            }
+        }
+
+        linked.push(c);
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    linked
+}
+
 impl Serialize for OidOf {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -79,9 +146,40 @@ mod test {
     use speculoos::prelude::*;
 
     use crate::conventional::changelog::release::ChangelogCommit;
+    use crate::conventional::changelog::serde::link_issue_references;
     use crate::conventional::commit::Commit;
     use crate::git::tag::Tag;
 
+    const ISSUE_URL: &str = "https://tracker.example.org/issues/{{id}}";
+
+    #[test]
+    fn should_link_single_issue_reference() {
+        let summary = link_issue_references("fix crash on startup #42", ISSUE_URL);
+
+        assert_that!(summary).is_equal_to(
+            "fix crash on startup [#42](https://tracker.example.org/issues/42)".to_string(),
+        )
+    }
+
+    #[test]
+    fn should_link_multiple_issue_references() {
+        let summary = link_issue_references("close #1, GH-23 and (#456)", ISSUE_URL);
+
+        assert_that!(summary).is_equal_to(
+            "close [#1](https://tracker.example.org/issues/1), \
+            [GH-23](https://tracker.example.org/issues/23) \
+            and ([#456](https://tracker.example.org/issues/456))"
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn should_not_link_non_issue_references() {
+        let summary = link_issue_references("use C# and color#fff, see [#12] or # 3", ISSUE_URL);
+
+        assert_that!(summary).is_equal_to("use C# and color#fff, see [#12] or # 3".to_string())
+    }
+
     #[test]
     fn should_serialize_tag() {
         let tag = Tag::new("1.0.0", Some(Oid::from_str("1234567890").unwrap())).unwrap();
@@ -112,8 +210,13 @@ mod test {
                     is_breaking_change: false,
                 },
                 author: "Jean Michel Doudou".to_string(),
+                author_email: None,
                 date: Utc::now().naive_utc(),
+                gitmoji: None,
+                ticket: None,
+                pull_request: None,
             },
+            stats: None,
         };
 
         let result = toml::to_string(&commit);
@@ -5,9 +5,13 @@ use std::path::PathBuf;
 const DEFAULT_TEMPLATE: &[u8] = include_bytes!("template/simple");
 const DEFAULT_TEMPLATE_NAME: &str = "default";
 const REMOTE_TEMPLATE: &[u8] = include_bytes!("template/remote");
-const REMOTE_TEMPLATE_NAME: &str = "remote";
+pub(crate) const REMOTE_TEMPLATE_NAME: &str = "remote";
 const FULL_HASH_TEMPLATE: &[u8] = include_bytes!("template/full_hash");
 const FULL_HASH_TEMPLATE_NAME: &str = "full_hash";
+const KEEPACHANGELOG_TEMPLATE: &[u8] = include_bytes!("template/keepachangelog");
+const KEEPACHANGELOG_TEMPLATE_NAME: &str = "keepachangelog";
+const FLAT_TEMPLATE: &[u8] = include_bytes!("template/flat");
+pub(crate) const FLAT_TEMPLATE_NAME: &str = "flat";
 
 #[derive(Debug, Default)]
 pub struct Template {
@@ -31,6 +35,8 @@ pub enum TemplateKind {
     Default,
     FullHash,
     Remote,
+    KeepAChangelog,
+    Flat,
     Custom(PathBuf),
 }
 
@@ -47,6 +53,8 @@ impl TemplateKind {
             DEFAULT_TEMPLATE_NAME => Ok(TemplateKind::Default),
             REMOTE_TEMPLATE_NAME => Ok(TemplateKind::Remote),
             FULL_HASH_TEMPLATE_NAME => Ok(TemplateKind::FullHash),
+            KEEPACHANGELOG_TEMPLATE_NAME => Ok(TemplateKind::KeepAChangelog),
+            FLAT_TEMPLATE_NAME => Ok(TemplateKind::Flat),
             path => {
                 let path = PathBuf::from(path);
                 if !path.exists() {
@@ -63,6 +71,8 @@ impl TemplateKind {
             TemplateKind::Default => Ok(DEFAULT_TEMPLATE.to_vec()),
             TemplateKind::Remote => Ok(REMOTE_TEMPLATE.to_vec()),
             TemplateKind::FullHash => Ok(FULL_HASH_TEMPLATE.to_vec()),
+            TemplateKind::KeepAChangelog => Ok(KEEPACHANGELOG_TEMPLATE.to_vec()),
+            TemplateKind::Flat => Ok(FLAT_TEMPLATE.to_vec()),
             TemplateKind::Custom(path) => std::fs::read(path),
         }
     }
@@ -72,6 +82,8 @@ impl TemplateKind {
             TemplateKind::Default => DEFAULT_TEMPLATE_NAME,
             TemplateKind::Remote => REMOTE_TEMPLATE_NAME,
             TemplateKind::FullHash => FULL_HASH_TEMPLATE_NAME,
+            TemplateKind::KeepAChangelog => KEEPACHANGELOG_TEMPLATE_NAME,
+            TemplateKind::Flat => FLAT_TEMPLATE_NAME,
             TemplateKind::Custom(_) => "custom_template",
         }
     }
@@ -102,14 +114,19 @@ impl RemoteContext {
         }
     }
 
+    pub(crate) fn platform_url(&self) -> String {
+        format!("https://{}", self.remote)
+    }
+
+    pub(crate) fn repository_url(&self) -> String {
+        format!("https://{}/{}/{}", self.remote, self.owner, self.repository)
+    }
+
     pub(crate) fn to_tera_context(&self) -> tera::Context {
         let mut context = tera::Context::new();
-        context.insert("platform", &format!("https://{}", self.remote.as_str()));
+        context.insert("platform", &self.platform_url());
         context.insert("owner", self.owner.as_str());
-        context.insert(
-            "repository_url",
-            &format!("https://{}/{}/{}", self.remote, self.owner, self.repository),
-        );
+        context.insert("repository_url", &self.repository_url());
 
         context
     }
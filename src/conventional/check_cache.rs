@@ -0,0 +1,116 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::settings::Settings;
+
+const CACHE_FILE: &str = ".cog-cache";
+
+/// Oids of the commits found compliant by previous `cog check --cache` runs, stored in the
+/// `.cog-cache` file of the git directory. The cache is discarded whenever the cog version
+/// or the settings, merged from the repository and user-global `cog.toml`, change.
+#[derive(Debug)]
+pub(crate) struct CheckCache {
+    path: PathBuf,
+    key: String,
+    oids: HashSet<String>,
+}
+
+impl CheckCache {
+    /// Load the cache of the repository, empty when missing, unreadable or outdated.
+    pub(crate) fn load(git_dir: &Path, settings: &Settings) -> Self {
+        let path = git_dir.join(CACHE_FILE);
+        let key = cache_key(settings);
+        let content = fs::read_to_string(&path).unwrap_or_default();
+        let mut lines = content.lines();
+
+        let oids = if lines.next() == Some(key.as_str()) {
+            lines.map(str::to_string).collect()
+        } else {
+            HashSet::new()
+        };
+
+        CheckCache { path, key, oids }
+    }
+
+    pub(crate) fn contains(&self, oid: &str) -> bool {
+        self.oids.contains(oid)
+    }
+
+    pub(crate) fn insert(&mut self, oid: String) {
+        self.oids.insert(oid);
+    }
+
+    pub(crate) fn save(&self) -> io::Result<()> {
+        let mut content = self.key.clone();
+        for oid in &self.oids {
+            content.push('\n');
+            content.push_str(oid);
+        }
+
+        fs::write(&self.path, content)
+    }
+}
+
+// Identify the rules commits were checked against: the cog version and the merged settings.
+// Settings are hashed through a `serde_json::Value` whose maps are sorted, so the key does
+// not depend on the iteration order of the settings hash maps.
+fn cache_key(settings: &Settings) -> String {
+    let settings = serde_json::to_value(settings)
+        .map(|settings| settings.to_string())
+        .unwrap_or_default();
+
+    let mut hasher = DefaultHasher::new();
+    settings.hash(&mut hasher);
+    format!("{} {:x}", env!("CARGO_PKG_VERSION"), hasher.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+    use sealed_test::prelude::*;
+    use speculoos::prelude::*;
+
+    use crate::conventional::check_cache::CheckCache;
+    use crate::settings::Settings;
+
+    #[sealed_test]
+    fn should_reload_saved_oids() -> Result<()> {
+        // Arrange
+        let dir = std::env::current_dir()?;
+        let settings = Settings::default();
+        let mut cache = CheckCache::load(&dir, &settings);
+        cache.insert("17f7e23081db15e9318aeb37529b1d473cf41cbe".to_string());
+        cache.save()?;
+
+        // Act
+        let cache = CheckCache::load(&dir, &settings);
+
+        // Assert
+        assert_that!(cache.contains("17f7e23081db15e9318aeb37529b1d473cf41cbe")).is_true();
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_discard_cache_on_settings_change() -> Result<()> {
+        // Arrange
+        let dir = std::env::current_dir()?;
+        let mut cache = CheckCache::load(&dir, &Settings::default());
+        cache.insert("17f7e23081db15e9318aeb37529b1d473cf41cbe".to_string());
+        cache.save()?;
+        fs::write("cog.toml", "ignore_merge_commits = true")?;
+        let settings = Settings::from_file(&dir.join("cog.toml"))?;
+
+        // Act
+        let cache = CheckCache::load(&dir, &settings);
+
+        // Assert
+        assert_that!(cache.contains("17f7e23081db15e9318aeb37529b1d473cf41cbe")).is_false();
+        Ok(())
+    }
+}
@@ -1,32 +1,71 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::{self, Formatter};
 
 use crate::conventional::error::ConventionalCommitError;
+use crate::settings::{InvalidUtf8Policy, Severity, SubjectCase};
 use crate::SETTINGS;
 use chrono::{NaiveDateTime, Utc};
 use colored::*;
-use conventional_commit_parser::commit::ConventionalCommit;
+use conventional_commit_parser::commit::{CommitType, ConventionalCommit, Footer, Separator};
 use git2::Commit as Git2Commit;
-use log::info;
+use lazy_static::lazy_static;
+use log::{info, warn};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+const SIGNOFF_TOKEN: &str = "Signed-off-by";
+
+lazy_static! {
+    // Invalid patterns are rejected when loading the settings.
+    static ref TICKET_PREFIX: Option<Regex> = SETTINGS
+        .commit
+        .ticket_prefix_pattern
+        .as_deref()
+        .and_then(|pattern| Regex::new(pattern).ok());
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct Commit {
     pub(crate) oid: String,
     pub(crate) message: ConventionalCommit,
     pub(crate) author: String,
+    pub(crate) author_email: Option<String>,
     pub(crate) date: NaiveDateTime,
+    pub(crate) gitmoji: Option<String>,
+    pub(crate) ticket: Option<String>,
+    pub(crate) pull_request: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 pub struct CommitConfig {
     pub changelog_title: String,
+    /// Description of the commit type displayed in `cog commit --help`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help_message: Option<String>,
+    /// Example commit message displayed in `cog commit --help`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    /// Description of the commit message argument for this type, e.g. the expected mood
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_help: Option<String>,
+    /// Description of the `--scope` argument for this type, e.g. the allowed scopes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_help: Option<String>,
+    /// Description of the `--body` argument for this type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_help: Option<String>,
 }
 
 impl CommitConfig {
     pub(crate) fn new(changelog_title: &str) -> Self {
         CommitConfig {
             changelog_title: changelog_title.to_string(),
+            help_message: None,
+            example: None,
+            message_help: None,
+            scope_help: None,
+            body_help: None,
         }
     }
 }
@@ -35,39 +74,174 @@ impl Commit {
     pub(crate) fn from_git_commit(
         commit: &Git2Commit,
     ) -> Result<Self, Box<ConventionalCommitError>> {
+        Commit::parse_git_commit(commit, false)
+    }
+
+    /// Parse a commit released by `cog bump` and `cog changelog`. Merge commits are left out
+    /// (`None`), except GitHub pull request merge commits when `changelog.parse_merge_pr` is
+    /// set, see [`Commit::from_merge_pull_request`].
+    pub(crate) fn from_released_commit(
+        commit: &Git2Commit,
+    ) -> Option<Result<Self, Box<ConventionalCommitError>>> {
+        if !commit.message().unwrap_or("").starts_with("Merge ") {
+            Some(Commit::from_git_commit(commit))
+        } else if SETTINGS.changelog.parse_merge_pr {
+            Commit::from_merge_pull_request(commit)
+        } else {
+            None
+        }
+    }
+
+    /// Parse the conventional message in the body of a GitHub pull request merge commit,
+    /// e.g. `Merge pull request #42 from org/branch\n\nfeat(api): ...`, keeping the pull
+    /// request number. `None` if `commit` is not such a merge commit or has no body.
+    pub(crate) fn from_merge_pull_request(
+        commit: &Git2Commit,
+    ) -> Option<Result<Self, Box<ConventionalCommitError>>> {
+        let message = String::from_utf8_lossy(commit.message_bytes());
+        let (header, body) = message.split_once('\n')?;
+        let pull_request = merge_pull_request_number(header)?;
+        let body = body.trim();
+        if body.is_empty() {
+            return None;
+        }
+
         let oid = commit.id().to_string();
+        let date = NaiveDateTime::from_timestamp(commit.time().seconds(), 0);
+        let author = commit.author().name().unwrap_or("").to_string();
+        let author_email = commit.author().email().map(str::to_string);
+
+        Some(
+            Commit::parse_message(oid, body, author, date, false).map(|commit| Commit {
+                author_email,
+                pull_request: Some(pull_request),
+                ..commit
+            }),
+        )
+    }
+
+    /// Same as [`Commit::from_git_commit`] but accepts commit types missing from the configuration.
+    pub(crate) fn from_git_commit_with_unknown_type(
+        commit: &Git2Commit,
+    ) -> Result<Self, Box<ConventionalCommitError>> {
+        Commit::parse_git_commit(commit, true)
+    }
+
+    /// Parse a commit message that is not backed by a git commit, checking it against the
+    /// configuration like any repository commit. The commit id is `not committed`,
+    /// the author defaults to `Unknown` and the date to now.
+    pub fn parse(
+        message: &str,
+        author: Option<&str>,
+        date: Option<NaiveDateTime>,
+    ) -> Result<Self, Box<ConventionalCommitError>> {
+        Commit::parse_message(
+            "not committed".to_string(),
+            message,
+            author.unwrap_or("Unknown").to_string(),
+            date.unwrap_or_else(|| Utc::now().naive_utc()),
+            false,
+        )
+    }
 
-        let commit = commit.to_owned();
+    fn parse_git_commit(
+        commit: &Git2Commit,
+        allow_unknown_type: bool,
+    ) -> Result<Self, Box<ConventionalCommitError>> {
+        let oid = commit.id().to_string();
         let date = NaiveDateTime::from_timestamp(commit.time().seconds(), 0);
-        let message = commit.message();
-        let git2_message = message.unwrap().to_owned();
         let author = commit.author().name().unwrap_or("").to_string();
+        if SETTINGS.commit.on_invalid_utf8 == InvalidUtf8Policy::Error && commit.message().is_none()
+        {
+            return Err(Box::new(ConventionalCommitError::InvalidUtf8 {
+                oid,
+                author,
+            }));
+        }
 
-        let message = git2_message.trim_end().trim_start();
-        let conventional_commit = conventional_commit_parser::parse(message);
+        let message = String::from_utf8_lossy(commit.message_bytes());
+        let message =
+            with_legacy_separator(message.trim(), SETTINGS.commit.legacy_separator.as_deref());
+
+        let author_email = commit.author().email().map(str::to_string);
+
+        Commit::parse_message(oid, &message, author, date, allow_unknown_type).map(|commit| {
+            Commit {
+                author_email,
+                ..commit
+            }
+        })
+    }
+
+    fn parse_message(
+        oid: String,
+        raw_message: &str,
+        author: String,
+        date: NaiveDateTime,
+        allow_unknown_type: bool,
+    ) -> Result<Self, Box<ConventionalCommitError>> {
+        let (ticket, message) = strip_ticket_prefix(raw_message.trim(), TICKET_PREFIX.as_ref());
+        let (gitmoji, message) = if SETTINGS.commit.gitmoji {
+            strip_gitmoji(message)
+        } else {
+            (None, message)
+        };
+
+        if has_empty_description(message) {
+            return Err(Box::new(ConventionalCommitError::EmptyDescription {
+                oid,
+                summary: Commit::short_summary_from_str(message.lines().next().unwrap_or("")),
+                author,
+            }));
+        }
+
+        let raw_type = raw_commit_type(message);
+        let breaking_change_keyword =
+            has_breaking_change_keyword(message, &SETTINGS.commit.breaking_change_keywords);
+        let conventional_commit = conventional_commit_parser::parse(&with_separator_space(message));
 
         match conventional_commit {
-            Ok(message) => {
+            Ok(mut message) => {
+                message.is_breaking_change |= breaking_change_keyword;
+                let pull_request = if SETTINGS.commit.extract_trailing_pr {
+                    extract_trailing_pull_request(&mut message.summary)
+                } else {
+                    None
+                };
+
                 let commit = Commit {
                     oid,
                     message,
                     author,
+                    author_email: None,
                     date,
+                    gitmoji: gitmoji.map(str::to_string),
+                    ticket: ticket.map(str::to_string),
+                    pull_request,
                 };
 
-                match &SETTINGS.commit_types().get(&commit.message.commit_type) {
-                    Some(_) => Ok(commit),
-                    None => Err(Box::new(ConventionalCommitError::CommitTypeNotAllowed {
+                if !allow_unknown_type
+                    && !is_commit_type_allowed(raw_type, &commit.message.commit_type)
+                {
+                    Err(Box::new(ConventionalCommitError::CommitTypeNotAllowed {
+                        oid: commit.oid.to_string(),
+                        summary: format_summary(&commit.message),
+                        commit_type: raw_type.to_string(),
+                        author: commit.author,
+                    }))
+                } else if let Some(scope) = invalid_scope(&commit.message) {
+                    Err(Box::new(ConventionalCommitError::InvalidScope {
                         oid: commit.oid.to_string(),
                         summary: format_summary(&commit.message),
-                        commit_type: commit.message.commit_type.to_string(),
+                        scope: scope.to_string(),
                         author: commit.author,
-                    })),
+                    }))
+                } else {
+                    Ok(commit)
                 }
             }
             Err(cause) => {
-                let message = git2_message.trim_end();
-                let summary = Commit::short_summary_from_str(message);
+                let summary = Commit::short_summary_from_str(raw_message.trim_end());
                 Err(Box::new(ConventionalCommitError::CommitFormat {
                     oid,
                     summary,
@@ -78,6 +252,94 @@ impl Commit {
         }
     }
 
+    /// Check this commit against the style rules of the `commit` settings `cog verify` and
+    /// `cog check` enforce. Parsing does not apply them, so bumps and changelogs still
+    /// account for commits breaking them.
+    pub(crate) fn lint(&self) -> Result<(), Box<ConventionalCommitError>> {
+        if self.message.scope.is_none()
+            && is_type_listed(&SETTINGS.commit.scope_required_types, &self.message)
+        {
+            return Err(Box::new(ConventionalCommitError::MissingScope {
+                oid: self.oid.to_string(),
+                summary: format_summary(&self.message),
+                commit_type: self.message.commit_type.to_string(),
+                author: self.author.clone(),
+            }));
+        }
+
+        if self.message.scope.is_some()
+            && is_type_listed(&SETTINGS.commit.scope_forbidden_types, &self.message)
+        {
+            return Err(Box::new(ConventionalCommitError::ForbiddenScope {
+                oid: self.oid.to_string(),
+                summary: format_summary(&self.message),
+                commit_type: self.message.commit_type.to_string(),
+                author: self.author.clone(),
+            }));
+        }
+
+        if SETTINGS.commit.require_signoff && !has_signoff(&self.message) {
+            return Err(Box::new(ConventionalCommitError::MissingSignoff {
+                oid: self.oid.to_string(),
+                summary: format_summary(&self.message),
+                author: self.author.clone(),
+            }));
+        }
+
+        if SETTINGS.commit.require_breaking_body && !has_breaking_change_description(&self.message)
+        {
+            return Err(Box::new(
+                ConventionalCommitError::MissingBreakingChangeDescription {
+                    oid: self.oid.to_string(),
+                    summary: format_summary(&self.message),
+                    author: self.author.clone(),
+                },
+            ));
+        }
+
+        if !has_subject_case(&self.message.summary, SETTINGS.commit.subject_case) {
+            return Err(Box::new(ConventionalCommitError::InvalidSubjectCase {
+                oid: self.oid.to_string(),
+                summary: format_summary(&self.message),
+                case: SETTINGS.commit.subject_case,
+                author: self.author.clone(),
+            }));
+        }
+
+        if let Some(err) = self
+            .body_line_length_error()
+            .filter(|_| SETTINGS.commit.body_line_length_severity == Severity::Error)
+        {
+            return Err(Box::new(err));
+        }
+
+        Ok(())
+    }
+
+    /// The error for the first body line longer than `commit.max_body_line_length`, if any.
+    pub(crate) fn body_line_length_error(&self) -> Option<ConventionalCommitError> {
+        let max = SETTINGS.commit.max_body_line_length?;
+        let (line, length) = long_body_line(self.message.body.as_deref()?, max)?;
+
+        Some(ConventionalCommitError::BodyLineTooLong {
+            oid: self.oid.to_string(),
+            summary: format_summary(&self.message),
+            line,
+            length,
+            max,
+            author: self.author.clone(),
+        })
+    }
+
+    /// Print the body line length error as a warning when its severity is `warning`.
+    pub(crate) fn warn_body_line_length(&self) {
+        if SETTINGS.commit.body_line_length_severity == Severity::Warning {
+            if let Some(err) = self.body_line_length_error() {
+                warn!("{}", err.to_string().trim_end());
+            }
+        }
+    }
+
     pub(crate) fn shorthand(&self) -> &str {
         if self.oid != "not committed" {
             &self.oid[0..6]
@@ -86,13 +348,20 @@ impl Commit {
         }
     }
 
-    pub fn get_log(&self) -> String {
+    /// Format this commit for `cog log`, with its full id or its shorthand.
+    /// The scope line is left out unless `show_scope` is set.
+    pub fn get_log(&self, full_hash: bool, show_scope: bool) -> String {
         let summary = &self.message.summary;
         let message_display = Commit::short_summary_from_str(summary).yellow();
         let author_format = "Author:".green().bold();
         let type_format = "Type:".green().bold();
         let scope_format = "Scope:".green().bold();
         let breaking_change = self.format_breaking_change();
+        let oid = if full_hash {
+            &self.oid
+        } else {
+            self.shorthand()
+        };
         let now = Utc::now().naive_utc();
         let elapsed = now - self.date;
         let elapsed = if elapsed.num_weeks() > 0 {
@@ -134,18 +403,58 @@ impl Commit {
             "now".to_string()
         };
 
+        let scope = if show_scope {
+            format!(
+                "\t{} {}\n",
+                scope_format,
+                self.message.scope.as_deref().unwrap_or("none")
+            )
+        } else {
+            String::new()
+        };
+
         format!(
-            "{}{} ({}) - {}\n\t{} {}\n\t{} {}\n\t{} {}\n",
+            "{}{} ({}) - {}\n\t{} {}\n\t{} {}\n{}",
             breaking_change,
             message_display,
-            self.shorthand().bold(),
+            oid.bold(),
             elapsed,
             author_format,
             self.author,
             type_format,
             self.message.commit_type,
-            scope_format,
-            self.message.scope.as_deref().unwrap_or("none"),
+            scope,
+        )
+    }
+
+    /// Format this commit as a markdown list item for `cog log --format markdown`.
+    pub fn get_markdown_log(&self, full_hash: bool, show_scope: bool) -> String {
+        let oid = if full_hash {
+            &self.oid
+        } else {
+            self.shorthand()
+        };
+        let breaking_change = if self.message.is_breaking_change {
+            "**BREAKING CHANGE** "
+        } else {
+            ""
+        };
+        let scope = self
+            .message
+            .scope
+            .as_deref()
+            .filter(|_| show_scope)
+            .map(|scope| format!("({})", scope))
+            .unwrap_or_default();
+
+        format!(
+            "- {}**{}{}:** {} - (`{}`) - {}",
+            breaking_change,
+            self.message.commit_type,
+            scope,
+            self.message.summary,
+            oid,
+            self.author
         )
     }
 
@@ -170,7 +479,7 @@ impl Commit {
 
 impl fmt::Display for Commit {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.get_log())
+        write!(f, "{}", self.get_log(false, true))
     }
 }
 
@@ -205,33 +514,325 @@ pub fn verify(
         return Ok(());
     }
 
-    let commit = conventional_commit_parser::parse(msg);
-
-    match commit {
-        Ok(commit) => match &SETTINGS.commit_types().get(&commit.commit_type) {
-            Some(_) => {
-                info!(
-                    "{}",
-                    Commit {
-                        oid: "not committed".to_string(),
-                        message: commit,
-                        date: Utc::now().naive_utc(),
-                        author: author.unwrap_or_else(|| "Unknown".to_string()),
-                    }
-                );
-                Ok(())
+    if with_separator_space(msg) != msg {
+        warn!("Missing space after `:`, the canonical commit header is `type(scope): description`");
+    }
+
+    match Commit::parse(msg, author.as_deref(), None)
+        .and_then(|commit| commit.lint().map(|_| commit))
+    {
+        Ok(commit) => {
+            commit.warn_body_line_length();
+            info!("{}", commit);
+            Ok(())
+        }
+        Err(err) => match *err {
+            ConventionalCommitError::CommitFormat { cause, .. } => {
+                Err(Box::new(ConventionalCommitError::ParseError(cause)))
             }
-            None => Err(Box::new(ConventionalCommitError::CommitTypeNotAllowed {
-                oid: "not committed".to_string(),
-                summary: format_summary(&commit),
-                commit_type: commit.commit_type.to_string(),
-                author: author.unwrap_or_else(|| "Unknown".to_string()),
-            })),
+            err => Err(Box::new(err)),
         },
-        Err(err) => Err(Box::new(ConventionalCommitError::ParseError(err))),
     }
 }
 
+// Pull request number of a `Merge pull request #42 from org/branch` merge commit header.
+fn merge_pull_request_number(header: &str) -> Option<u64> {
+    let reference = header.trim().strip_prefix("Merge pull request #")?;
+    let (number, source) = reference.split_once(' ').unwrap_or((reference, ""));
+    if !source.is_empty() && !source.starts_with("from ") {
+        return None;
+    }
+
+    number.parse().ok()
+}
+
+/// Fix trivial formatting issues: trailing whitespaces, repeated spaces in the header
+/// and a missing space after the commit type separator.
+pub fn fix_message(message: &str) -> String {
+    let mut lines: Vec<String> = message
+        .lines()
+        .map(|line| line.trim_end().to_string())
+        .collect();
+
+    if let Some(header) = lines.first_mut() {
+        *header = header
+            .split(' ')
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if let Some(fixed) = add_separator_space(header) {
+            *header = fixed;
+        }
+    }
+
+    let mut fixed = lines.join("\n");
+    if message.ends_with('\n') {
+        fixed.push('\n');
+    }
+
+    fixed
+}
+
+// Insert the space missing after the separator of a `type(scope):description` header,
+// `None` if the header is not of this form.
+fn add_separator_space(header: &str) -> Option<String> {
+    let (prefix, description) = header.split_once(':')?;
+    let is_type_prefix = !prefix.is_empty()
+        && !prefix.contains(char::is_whitespace)
+        && (!prefix.contains('(') || prefix.trim_end_matches('!').ends_with(')'));
+
+    if is_type_prefix && !description.is_empty() && !description.starts_with(char::is_whitespace) {
+        Some(format!("{}: {}", prefix, description))
+    } else {
+        None
+    }
+}
+
+// Tolerate headers without a space after the commit type separator, e.g. `feat:message`.
+pub(crate) fn with_separator_space(message: &str) -> Cow<'_, str> {
+    let header = message.lines().next().unwrap_or("");
+    match add_separator_space(header) {
+        Some(fixed) => Cow::Owned(format!("{}{}", fixed, &message[header.len()..])),
+        None => Cow::Borrowed(message),
+    }
+}
+
+// Rewrite a legacy `type(scope)<separator>description` header, such as `feat - description`,
+// to the conventional `type(scope): description` form.
+fn with_legacy_separator<'a>(message: &'a str, separator: Option<&str>) -> Cow<'a, str> {
+    let header = message.lines().next().unwrap_or("");
+    let legacy_header = separator
+        .filter(|separator| !separator.is_empty())
+        .and_then(|separator| header.split_once(separator))
+        .filter(|(prefix, description)| {
+            !prefix.is_empty()
+                && !prefix.contains(|c: char| c.is_whitespace() || c == ':')
+                && !description.trim().is_empty()
+        });
+
+    match legacy_header {
+        Some((prefix, description)) => Cow::Owned(format!(
+            "{}: {}{}",
+            prefix,
+            description.trim_start(),
+            &message[header.len()..]
+        )),
+        None => Cow::Borrowed(message),
+    }
+}
+
+// The commit type as written in the message header, before any scope,
+// breaking change mark or separator.
+fn raw_commit_type(message: &str) -> &str {
+    message.split(['(', '!', ':']).next().unwrap_or("").trim()
+}
+
+// True for headers such as `feat:` or `feat(scope):   ` where nothing follows the separator.
+fn has_empty_description(message: &str) -> bool {
+    let header = message.lines().next().unwrap_or("");
+    match header.split_once(':') {
+        Some((prefix, description)) => {
+            !prefix.is_empty()
+                && !prefix.contains(char::is_whitespace)
+                && description.trim().is_empty()
+        }
+        None => false,
+    }
+}
+
+// Scopes are restricted to letters, digits and `-`, `_`, `.`, `/`, `,` or `*`:
+// `ci-cd` and `web.ui` are valid scopes but `a:b` is not.
+fn invalid_scope(commit: &ConventionalCommit) -> Option<&str> {
+    commit.scope.as_deref().filter(|scope| {
+        !scope
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | ',' | '*'))
+    })
+}
+
+// Split a leading gitmoji, either a `:shortcode:` or an unicode emoji, from the commit message.
+fn strip_gitmoji(message: &str) -> (Option<&str>, &str) {
+    let (prefix, rest) = match message.split_once(char::is_whitespace) {
+        Some(parts) => parts,
+        None => return (None, message),
+    };
+
+    let is_shortcode = prefix.len() > 2
+        && prefix.starts_with(':')
+        && prefix.ends_with(':')
+        && prefix[1..prefix.len() - 1]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '+');
+
+    let is_emoji = prefix
+        .chars()
+        .all(|c| !c.is_ascii() && !c.is_alphanumeric());
+
+    if is_shortcode || is_emoji {
+        (Some(prefix), rest.trim_start())
+    } else {
+        (None, message)
+    }
+}
+
+// Remove a trailing pull request reference, such as the ` (#42)` GitHub appends to squash merged
+// commits, from a commit description and return its number.
+fn extract_trailing_pull_request(summary: &mut String) -> Option<u64> {
+    let (description, reference) = summary.trim_end().rsplit_once(" (#")?;
+    let number = reference
+        .strip_suffix(')')
+        .filter(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))?
+        .parse()
+        .ok()?;
+
+    let length = description.trim_end().len();
+    summary.truncate(length);
+    Some(number)
+}
+
+// Split a leading ticket reference matching `pattern`, such as `[PROJ-123]`, from the commit
+// message. The ticket is the first capture group if any, the whole match without brackets otherwise.
+fn strip_ticket_prefix<'a>(
+    message: &'a str,
+    pattern: Option<&Regex>,
+) -> (Option<&'a str>, &'a str) {
+    let captures = match pattern.and_then(|pattern| pattern.captures(message)) {
+        Some(captures) => captures,
+        None => return (None, message),
+    };
+
+    let prefix = captures.get(0).expect("a match always has a group 0");
+    if prefix.start() != 0 || prefix.end() == 0 {
+        return (None, message);
+    }
+
+    let ticket = match captures.get(1) {
+        Some(group) => group.as_str(),
+        None => prefix
+            .as_str()
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']'),
+    };
+
+    (Some(ticket), message[prefix.end()..].trim_start())
+}
+
+// `CommitType::from` is case insensitive, when `case_insensitive_types` is disabled
+// we only accept commit types written exactly as their canonical or configured form.
+fn is_commit_type_allowed(raw_type: &str, commit_type: &CommitType) -> bool {
+    if !SETTINGS.commit_types().contains_key(commit_type) {
+        return false;
+    }
+
+    SETTINGS.commit.case_insensitive_types()
+        || raw_type == commit_type.as_ref()
+        || SETTINGS.commit_types.contains_key(raw_type)
+}
+
+pub(crate) fn is_type_listed(types: &[String], commit: &ConventionalCommit) -> bool {
+    types
+        .iter()
+        .any(|commit_type| CommitType::from(commit_type.as_str()) == commit.commit_type)
+}
+
+pub(crate) fn signoff_footer(name: &str, email: &str) -> Footer {
+    Footer {
+        token: SIGNOFF_TOKEN.to_string(),
+        content: format!("{} <{}>", name, email),
+        token_separator: Separator::Colon,
+    }
+}
+
+// True if a line after the header starts with one of the configured breaking change
+// keywords used as a footer token, e.g. `CAMBIO IMPORTANTE: ...` or `CAMBIO IMPORTANTE #12`.
+fn has_breaking_change_keyword(message: &str, keywords: &[String]) -> bool {
+    message.lines().skip(1).any(|line| {
+        keywords
+            .iter()
+            .filter(|keyword| !keyword.is_empty())
+            .filter_map(|keyword| line.strip_prefix(keyword.as_str()))
+            .any(|rest| rest.starts_with(": ") || rest.starts_with(" #"))
+    })
+}
+
+// Only the first letter of the description is constrained, descriptions starting with
+// a digit or a symbol always match.
+fn has_subject_case(description: &str, case: SubjectCase) -> bool {
+    match (description.trim_start().chars().next(), case) {
+        (Some(first), SubjectCase::Lower) => !first.is_uppercase(),
+        (Some(first), SubjectCase::Sentence) => !first.is_lowercase(),
+        _ => true,
+    }
+}
+
+// The 1-based number and the length of the first body line longer than `max` characters.
+// Lines containing a URL are exempted since URLs cannot be wrapped.
+fn long_body_line(body: &str, max: usize) -> Option<(usize, usize)> {
+    body.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.contains("://"))
+        .map(|(idx, line)| (idx + 1, line.chars().count()))
+        .find(|(_, length)| *length > max)
+}
+
+// Non breaking commits always pass, breaking ones need a body or a non empty breaking
+// change footer, including the configured `breaking_change_keywords` ones.
+fn has_breaking_change_description(commit: &ConventionalCommit) -> bool {
+    let has_body = commit
+        .body
+        .as_deref()
+        .is_some_and(|body| !body.trim().is_empty());
+    let has_breaking_footer = commit.footers.iter().any(|footer| {
+        (footer.is_breaking_change()
+            || SETTINGS
+                .commit
+                .breaking_change_keywords
+                .contains(&footer.token))
+            && !footer.content.trim().is_empty()
+    });
+
+    !commit.is_breaking_change || has_body || has_breaking_footer
+}
+
+fn has_signoff(commit: &ConventionalCommit) -> bool {
+    commit
+        .footers
+        .iter()
+        .any(|footer| footer.token == SIGNOFF_TOKEN && is_valid_signoff(&footer.content))
+}
+
+// A sign-off must read `Name <email>`, with a non empty name and a plausible email address.
+fn is_valid_signoff(signoff: &str) -> bool {
+    let signoff = signoff.trim();
+    let (name, email) = match signoff.strip_suffix('>').and_then(|s| s.split_once('<')) {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let valid_email = match email.split_once('@') {
+        Some((user, domain)) => {
+            !user.is_empty()
+                && !domain.is_empty()
+                && !email.contains(|c: char| c.is_whitespace() || c == '<' || c == '>')
+        }
+        None => false,
+    };
+
+    !name.trim().is_empty() && valid_email
+}
+
+// Whether `commit` is left out of the history, its message not being valid UTF-8
+// and `on_invalid_utf8` set to `skip`.
+pub(crate) fn is_skipped_commit(commit: &Git2Commit) -> bool {
+    let skipped =
+        SETTINGS.commit.on_invalid_utf8 == InvalidUtf8Policy::Skip && commit.message().is_none();
+    if skipped {
+        warn!("Skipping commit {} with a non UTF-8 message", commit.id());
+    }
+    skipped
+}
+
 pub(crate) fn format_summary(commit: &ConventionalCommit) -> String {
     match &commit.scope {
         None => format!("{}: {}", commit.commit_type, commit.summary,),
@@ -243,16 +844,30 @@ pub(crate) fn format_summary(commit: &ConventionalCommit) -> String {
 
 #[cfg(test)]
 mod test {
-    use crate::conventional::commit::{format_summary, verify, Commit};
+    use crate::conventional::commit::{
+        extract_trailing_pull_request, fix_message, format_summary, has_breaking_change_keyword,
+        has_empty_description, has_signoff, has_subject_case, merge_pull_request_number,
+        raw_commit_type, strip_gitmoji, strip_ticket_prefix, verify, with_legacy_separator,
+        with_separator_space, Commit,
+    };
 
     use chrono::NaiveDateTime;
     use cmd_lib::run_fun;
 
+    use crate::conventional::error::ConventionalCommitError;
+    use crate::settings::SubjectCase;
     use crate::Repository;
     use anyhow::Result;
     use conventional_commit_parser::commit::{CommitType, ConventionalCommit, Footer, Separator};
+    use conventional_commit_parser::error::{ParseError, ParseErrorKind};
     use git2::Oid;
     use indoc::indoc;
+    use proptest::collection::vec;
+    use proptest::option;
+    use proptest::prelude::*;
+    use proptest::sample::select;
+    use proptest::test_runner::TestCaseError;
+    use regex::Regex;
     use sealed_test::prelude::*;
     use speculoos::prelude::*;
 
@@ -368,6 +983,81 @@ mod test {
         assert_that!(result).is_err();
     }
 
+    #[test]
+    fn verify_with_hyphenated_and_dotted_scopes_succeeds() {
+        // Arrange
+        let messages = ["feat(ci-cd): x", "feat(web.ui): x"];
+
+        for message in messages {
+            // Act
+            let result = verify(None, message, false);
+
+            // Assert
+            assert_that!(result).is_ok();
+        }
+    }
+
+    #[test]
+    fn should_parse_hyphenated_and_dotted_scopes_as_single_scope() {
+        // Act
+        let hyphenated = Commit::parse("feat(ci-cd): x", None, None);
+        let dotted = Commit::parse("feat(web.ui): x", None, None);
+
+        // Assert
+        let scope = |commit: Result<Commit, _>| commit.map(|commit| commit.message.scope);
+        assert_that!(scope(hyphenated)).is_ok_containing(Some("ci-cd".to_string()));
+        assert_that!(scope(dotted)).is_ok_containing(Some("web.ui".to_string()));
+    }
+
+    #[test]
+    fn should_reject_invalid_scope_when_parsing() {
+        // Act
+        let result = Commit::parse("feat(web:ui): x", None, None);
+
+        // Assert
+        assert!(matches!(
+            *result.unwrap_err(),
+            ConventionalCommitError::InvalidScope { ref scope, .. } if scope == "web:ui"
+        ));
+    }
+
+    #[test]
+    fn verify_with_invalid_scope_fails() {
+        // Arrange
+        let message = "feat(web:ui): x";
+
+        // Act
+        let result = verify(None, message, false);
+
+        // Assert
+        assert_that!(result.unwrap_err().to_string()).contains("Invalid scope");
+    }
+
+    #[test]
+    fn verify_with_whitespace_in_scope_fails() {
+        // Arrange
+        let message = "feat(web ui): x";
+
+        // Act
+        let result = verify(None, message, false);
+
+        // Assert
+        assert!(matches!(
+            *result.unwrap_err(),
+            ConventionalCommitError::ParseError(ParseError {
+                kind: ParseErrorKind::UnexpectedWhitespaceOrNewLine,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn should_get_raw_commit_type() {
+        assert_that!(raw_commit_type("Feat(parser)!: a commit")).is_equal_to("Feat");
+        assert_that!(raw_commit_type("FIX: a commit")).is_equal_to("FIX");
+        assert_that!(raw_commit_type("chore!: a commit")).is_equal_to("chore");
+    }
+
     #[test]
     fn verify_with_comment_and_trailing_whitespace_succeeds() -> Result<()> {
         let message = indoc!(
@@ -404,7 +1094,12 @@ mod test {
             },
 
             author: "".to_string(),
+
+            author_email: None,
             date: NaiveDateTime::from_timestamp(0, 0),
+            gitmoji: None,
+            ticket: None,
+            pull_request: None,
         };
 
         // Act
@@ -429,7 +1124,12 @@ mod test {
             },
 
             author: "".to_string(),
+
+            author_email: None,
             date: NaiveDateTime::from_timestamp(0, 0),
+            gitmoji: None,
+            ticket: None,
+            pull_request: None,
         };
 
         // Act
@@ -451,7 +1151,7 @@ mod test {
 
         let oid = Oid::from_str(&oid).unwrap();
         let repo = Repository::open(".").unwrap();
-        let commit = repo.0.find_commit(oid).expect("Unable to find commit");
+        let commit = repo.git.find_commit(oid).expect("Unable to find commit");
 
         // Act
         let commit = Commit::from_git_commit(&commit);
@@ -460,6 +1160,150 @@ mod test {
         assert_that!(commit).is_ok();
     }
 
+    #[sealed_test]
+    fn should_map_conventional_commit_without_space_after_separator() {
+        // Arrange
+        let oid = run_fun!(
+            git init;
+            git commit --allow-empty -q -m "feat(parser):a commit";
+            git log --format=%H -n 1;
+        )
+        .unwrap();
+
+        let oid = Oid::from_str(&oid).unwrap();
+        let repo = Repository::open(".").unwrap();
+        let commit = repo.git.find_commit(oid).expect("Unable to find commit");
+
+        // Act
+        let commit = Commit::from_git_commit(&commit).unwrap();
+
+        // Assert
+        assert_that!(commit.message.commit_type).is_equal_to(CommitType::Feature);
+        assert_that!(commit.message.scope).is_equal_to(Some("parser".to_string()));
+        assert_that!(commit.message.summary).is_equal_to("a commit".to_string());
+    }
+
+    #[test]
+    fn should_add_missing_separator_space() {
+        assert_that!(with_separator_space("feat:x").as_ref()).is_equal_to("feat: x");
+        assert_that!(with_separator_space("feat(ci):x\n\nbody").as_ref())
+            .is_equal_to("feat(ci): x\n\nbody");
+        assert_that!(with_separator_space("feat!:x").as_ref()).is_equal_to("feat!: x");
+        assert_that!(with_separator_space("feat: x").as_ref()).is_equal_to("feat: x");
+        assert_that!(with_separator_space("feat(a:b): x").as_ref()).is_equal_to("feat(a:b): x");
+    }
+
+    #[test]
+    fn should_rewrite_legacy_separator_when_enabled() {
+        let separator = Some(" - ");
+
+        assert_that!(with_legacy_separator("feat - add x", separator).as_ref())
+            .is_equal_to("feat: add x");
+        assert_that!(with_legacy_separator("fix(api) - y\n\nbody", separator).as_ref())
+            .is_equal_to("fix(api): y\n\nbody");
+        assert_that!(with_legacy_separator("feat: a - b", separator).as_ref())
+            .is_equal_to("feat: a - b");
+        assert_that!(with_legacy_separator("feat - ", separator).as_ref()).is_equal_to("feat - ");
+    }
+
+    #[test]
+    fn should_keep_legacy_separator_when_disabled() {
+        assert_that!(with_legacy_separator("feat - add x", None).as_ref())
+            .is_equal_to("feat - add x");
+    }
+
+    #[test]
+    fn verify_without_space_after_separator_succeeds() {
+        // Arrange
+        let message = "feat:x";
+
+        // Act
+        let result = verify(None, message, false);
+
+        // Assert
+        assert_that!(result).is_ok();
+    }
+
+    #[test]
+    fn should_detect_configured_breaking_change_keyword() {
+        let keywords = vec!["CAMBIO IMPORTANTE".to_string()];
+
+        assert_that!(has_breaking_change_keyword(
+            "feat: x\n\nCAMBIO IMPORTANTE: the api changed",
+            &keywords
+        ))
+        .is_true();
+        assert_that!(has_breaking_change_keyword(
+            "feat: x\n\nCAMBIO IMPORTANTE #12",
+            &keywords
+        ))
+        .is_true();
+        assert_that!(has_breaking_change_keyword(
+            "feat: x\n\nCAMBIO IMPORTANTE sin dos puntos",
+            &keywords
+        ))
+        .is_false();
+        assert_that!(has_breaking_change_keyword(
+            "CAMBIO IMPORTANTE: x",
+            &keywords
+        ))
+        .is_false();
+        assert_that!(has_breaking_change_keyword(
+            "feat: x\n\nCAMBIO IMPORTANTE: y",
+            &[]
+        ))
+        .is_false();
+    }
+
+    #[test]
+    fn should_parse_commit_from_message() {
+        // Arrange
+        let message = "feat(parser): parse messages\n\nThe body";
+        let date = NaiveDateTime::from_timestamp(1_600_000_000, 0);
+
+        // Act
+        let commit = Commit::parse(message, Some("Jane Doe"), Some(date));
+
+        // Assert
+        let commit = commit.expect("valid commit");
+        assert_that!(commit.oid).is_equal_to("not committed".to_string());
+        assert_that!(commit.author).is_equal_to("Jane Doe".to_string());
+        assert_that!(commit.date).is_equal_to(date);
+        assert_that!(commit.message.commit_type).is_equal_to(CommitType::Feature);
+        assert_that!(commit.message.scope).is_equal_to(Some("parser".to_string()));
+        assert_that!(commit.message.summary).is_equal_to("parse messages".to_string());
+        assert_that!(commit.message.body).is_equal_to(Some("The body".to_string()));
+    }
+
+    #[test]
+    fn should_parse_commit_with_default_metadata() {
+        // Act
+        let commit = Commit::parse("fix: a fix", None, None);
+
+        // Assert
+        let commit = commit.expect("valid commit");
+        assert_that!(commit.author).is_equal_to("Unknown".to_string());
+        assert_that!(commit.message.commit_type).is_equal_to(CommitType::BugFix);
+    }
+
+    #[test]
+    fn should_fail_to_parse_non_conventional_message() {
+        // Act
+        let commit = Commit::parse("not a conventional commit", None, None);
+
+        // Assert
+        assert_that!(commit).is_err();
+    }
+
+    #[test]
+    fn should_fail_to_parse_unknown_commit_type() {
+        // Act
+        let commit = Commit::parse("toto: a commit", None, None);
+
+        // Assert
+        assert_that!(commit).is_err();
+    }
+
     #[sealed_test]
     fn map_conventional_commit_should_fail_with_invalid_type() {
         // Arrange
@@ -472,7 +1316,7 @@ mod test {
 
         let oid = Oid::from_str(&oid_str).unwrap();
         let repo = Repository::open(".").unwrap();
-        let commit = repo.0.find_commit(oid).expect("Unable to find commit");
+        let commit = repo.git.find_commit(oid).expect("Unable to find commit");
 
         // Act
         let commit = Commit::from_git_commit(&commit);
@@ -493,7 +1337,7 @@ mod test {
 
         let oid = Oid::from_str(&oid_str).unwrap();
         let repo = Repository::open(".").unwrap();
-        let commit = repo.0.find_commit(oid).expect("Unable to find commit");
+        let commit = repo.git.find_commit(oid).expect("Unable to find commit");
 
         // Act
         let commit = Commit::from_git_commit(&commit);
@@ -501,4 +1345,380 @@ mod test {
         // Assert
         assert_that!(commit).is_err();
     }
+
+    #[test]
+    fn should_validate_signoff_trailer() {
+        // Arrange
+        let signed = "feat: a feature\n\nSigned-off-by: Jane Doe <jane@example.org>";
+        let unsigned = "feat: a feature\n\nReviewed-by: Jane Doe <jane@example.org>";
+        let malformed = [
+            "feat: a feature\n\nSigned-off-by: Jane Doe",
+            "feat: a feature\n\nSigned-off-by: <jane@example.org>",
+            "feat: a feature\n\nSigned-off-by: Jane Doe <jane.example.org>",
+        ];
+
+        // Act
+        let signed = conventional_commit_parser::parse(signed).unwrap();
+        let unsigned = conventional_commit_parser::parse(unsigned).unwrap();
+
+        // Assert
+        assert_that!(has_signoff(&signed)).is_true();
+        assert_that!(has_signoff(&unsigned)).is_false();
+        for message in malformed {
+            let commit = conventional_commit_parser::parse(message).unwrap();
+            assert_that!(has_signoff(&commit)).is_false();
+        }
+    }
+
+    #[test]
+    fn should_strip_gitmoji_shortcode() {
+        // Arrange
+        let message = ":sparkles: feat(api): add an endpoint";
+
+        // Act
+        let (gitmoji, message) = strip_gitmoji(message);
+        let commit = conventional_commit_parser::parse(message).unwrap();
+
+        // Assert
+        assert_that!(gitmoji).is_equal_to(Some(":sparkles:"));
+        assert_that!(commit.commit_type).is_equal_to(CommitType::Feature);
+        assert_that!(commit.scope).is_equal_to(Some("api".to_string()));
+        assert_that!(commit.summary).is_equal_to("add an endpoint".to_string());
+    }
+
+    #[test]
+    fn should_strip_unicode_gitmoji() {
+        // Arrange
+        let message = "✨ feat(api): add an endpoint";
+
+        // Act
+        let (gitmoji, message) = strip_gitmoji(message);
+        let commit = conventional_commit_parser::parse(message).unwrap();
+
+        // Assert
+        assert_that!(gitmoji).is_equal_to(Some("✨"));
+        assert_that!(commit.commit_type).is_equal_to(CommitType::Feature);
+        assert_that!(commit.scope).is_equal_to(Some("api".to_string()));
+    }
+
+    #[test]
+    fn should_not_strip_regular_commit_type() {
+        // Arrange
+        let message = "feat(api): add an endpoint";
+
+        // Act
+        let (gitmoji, stripped) = strip_gitmoji(message);
+
+        // Assert
+        assert_that!(gitmoji).is_none();
+        assert_that!(stripped).is_equal_to(message);
+    }
+
+    #[test]
+    fn should_strip_ticket_prefix() {
+        // Arrange
+        let pattern = Regex::new(r"\[[A-Z]+-\d+\]").unwrap();
+        let message = "[PROJ-123] feat(api): add an endpoint";
+
+        // Act
+        let (ticket, message) = strip_ticket_prefix(message, Some(&pattern));
+        let commit = conventional_commit_parser::parse(message).unwrap();
+
+        // Assert
+        assert_that!(ticket).is_equal_to(Some("PROJ-123"));
+        assert_that!(commit.commit_type).is_equal_to(CommitType::Feature);
+        assert_that!(commit.scope).is_equal_to(Some("api".to_string()));
+        assert_that!(commit.summary).is_equal_to("add an endpoint".to_string());
+    }
+
+    #[test]
+    fn should_capture_ticket_prefix_group() {
+        // Arrange
+        let pattern = Regex::new(r"([A-Z]+-\d+):").unwrap();
+
+        // Act
+        let (ticket, message) = strip_ticket_prefix("PROJ-7: fix: a bug", Some(&pattern));
+
+        // Assert
+        assert_that!(ticket).is_equal_to(Some("PROJ-7"));
+        assert_that!(message).is_equal_to("fix: a bug");
+    }
+
+    #[test]
+    fn should_only_strip_leading_ticket_prefix() {
+        // Arrange
+        let pattern = Regex::new(r"\[[A-Z]+-\d+\]").unwrap();
+        let message = "feat: add an endpoint [PROJ-123]";
+
+        // Act
+        let (ticket, stripped) = strip_ticket_prefix(message, Some(&pattern));
+
+        // Assert
+        assert_that!(ticket).is_none();
+        assert_that!(stripped).is_equal_to(message);
+        assert_that!(strip_ticket_prefix("[PROJ-1] feat: a", None))
+            .is_equal_to((None, "[PROJ-1] feat: a"));
+    }
+
+    #[test]
+    fn should_extract_trailing_pull_request() {
+        // Arrange
+        let mut summary = "add thing (#42)".to_string();
+
+        // Act
+        let pull_request = extract_trailing_pull_request(&mut summary);
+
+        // Assert
+        assert_that!(pull_request).is_equal_to(Some(42));
+        assert_that!(summary).is_equal_to("add thing".to_string());
+    }
+
+    #[test]
+    fn should_only_extract_trailing_pull_request_reference() {
+        for description in [
+            "add thing (#42) again",
+            "add thing (#4a2)",
+            "add thing (#)",
+            "(#42)",
+        ] {
+            // Arrange
+            let mut summary = description.to_string();
+
+            // Act
+            let pull_request = extract_trailing_pull_request(&mut summary);
+
+            // Assert
+            assert_that!(pull_request).is_none();
+            assert_that!(summary.as_str()).is_equal_to(description);
+        }
+    }
+
+    #[test]
+    fn verify_with_empty_description_fails() {
+        for message in ["feat:", "feat: ", "feat:   ", "feat(scope):  \n\nthe body"] {
+            // Act
+            let result = verify(None, message, false);
+
+            // Assert
+            assert_that!(has_empty_description(message)).is_true();
+            assert_that!(result.unwrap_err().to_string()).contains("Commit description is empty");
+        }
+    }
+
+    #[test]
+    fn should_fix_message() {
+        // Arrange
+        let message = "feat(scope):add a feature  \n\nthe body \n# a comment\n";
+
+        // Act
+        let fixed = fix_message(message);
+
+        // Assert
+        assert_that!(fixed)
+            .is_equal_to("feat(scope): add a feature\n\nthe body\n# a comment\n".to_string());
+    }
+
+    #[test]
+    fn should_match_subject_case() {
+        assert_that!(has_subject_case("add a feature", SubjectCase::Lower)).is_true();
+        assert_that!(has_subject_case("Add a feature", SubjectCase::Lower)).is_false();
+        assert_that!(has_subject_case("Add a feature", SubjectCase::Sentence)).is_true();
+        assert_that!(has_subject_case("add a feature", SubjectCase::Sentence)).is_false();
+        assert_that!(has_subject_case("Add a feature", SubjectCase::Any)).is_true();
+        assert_that!(has_subject_case("add a feature", SubjectCase::Any)).is_true();
+        assert_that!(has_subject_case("404 page", SubjectCase::Sentence)).is_true();
+        assert_that!(has_subject_case("`cog` command", SubjectCase::Lower)).is_true();
+    }
+
+    #[test]
+    fn should_get_merge_pull_request_number() {
+        // Act
+        let number = merge_pull_request_number("Merge pull request #42 from org/branch");
+
+        // Assert
+        assert_that!(number).is_some().is_equal_to(42);
+    }
+
+    #[test]
+    fn should_not_get_number_of_other_merge_commits() {
+        // Act
+        let branch = merge_pull_request_number("Merge branch 'main' into feature");
+        let malformed = merge_pull_request_number("Merge pull request #42a from org/branch");
+
+        // Assert
+        assert_that!(branch).is_none();
+        assert_that!(malformed).is_none();
+    }
+
+    #[test]
+    fn should_collapse_header_spaces() {
+        // Arrange
+        let message = "fix:  remove  the double spaces\n\nkeep  the body";
+
+        // Act
+        let fixed = fix_message(message);
+
+        // Assert
+        assert_that!(fixed)
+            .is_equal_to("fix: remove the double spaces\n\nkeep  the body".to_string());
+    }
+
+    #[test]
+    fn should_not_fix_non_conventional_message() {
+        // Arrange
+        let message = "add a feature";
+
+        // Act
+        let fixed = fix_message(message);
+
+        // Assert
+        assert_that!(fixed).is_equal_to(message.to_string());
+    }
+
+    // Fragments a commit message is randomly assembled from, biased towards the characters
+    // the header parsing splits on and multi-byte characters to catch bad slicing.
+    const FUZZ_FRAGMENTS: [&str; 24] = [
+        "feat",
+        "fix",
+        "chore",
+        "(",
+        ")",
+        "!",
+        ":",
+        ": ",
+        " ",
+        "  ",
+        "\n",
+        "\n\n",
+        "#",
+        "-",
+        "BREAKING CHANGE",
+        "Refs",
+        "é",
+        "🎉",
+        ":sparkles:",
+        "[PROJ-1]",
+        "scope",
+        "a",
+        "\t",
+        "",
+    ];
+    const COMMIT_TYPES: [&str; 6] = ["feat", "fix", "chore", "docs", "refactor", "perf"];
+    const WORDS: [&str; 8] = [
+        "add", "parser", "remove", "the", "crash", "élan", "support", "42",
+    ];
+
+    fn message() -> impl Strategy<Value = String> {
+        vec(select(FUZZ_FRAGMENTS.to_vec()), 0..12).prop_map(|fragments| fragments.concat())
+    }
+
+    fn words() -> impl Strategy<Value = String> {
+        vec(select(WORDS.to_vec()), 1..6).prop_map(|words| words.join(" "))
+    }
+
+    fn conventional_message() -> impl Strategy<Value = String> {
+        (
+            select(COMMIT_TYPES.to_vec()),
+            option::of(select(WORDS.to_vec())),
+            any::<bool>(),
+            words(),
+            words(),
+            words(),
+        )
+            .prop_map(
+                |(commit_type, scope, is_breaking_change, summary, body, refs)| {
+                    format!(
+                        "{}{}{}: {}\n\n{}\n\nRefs: {}",
+                        commit_type,
+                        scope
+                            .map(|scope| format!("({})", scope))
+                            .unwrap_or_default(),
+                        if is_breaking_change { "!" } else { "" },
+                        summary,
+                        body,
+                        refs,
+                    )
+                },
+            )
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn should_not_panic_on_random_messages(message in message()) {
+            let _ = Commit::parse(&message, None, None);
+            let _ = fix_message(&message);
+            let _ = with_separator_space(&message);
+            let _ = with_legacy_separator(&message, Some(" - "));
+            let _ = strip_gitmoji(&message);
+            let _ = has_empty_description(&message);
+        }
+
+        #[test]
+        fn should_parse_generated_conventional_messages(
+            commit_type in select(COMMIT_TYPES.to_vec()),
+            scope in option::of(select(WORDS.to_vec())),
+            is_breaking_change in any::<bool>(),
+            summary in words(),
+            body in option::of(words()),
+            refs in option::of(words()),
+        ) {
+            // Arrange
+            let mut message = commit_type.to_string();
+            if let Some(scope) = scope {
+                message.push_str(&format!("({})", scope));
+            }
+            if is_breaking_change {
+                message.push('!');
+            }
+            message.push_str(&format!(": {}", summary));
+            if let Some(body) = &body {
+                message.push_str(&format!("\n\n{}", body));
+            }
+            if let Some(refs) = &refs {
+                message.push_str(&format!("\n\nRefs: {}", refs));
+            }
+
+            // Act
+            let commit = Commit::parse(&message, None, None);
+
+            // Assert
+            let commit = match commit {
+                Ok(commit) => commit.message,
+                Err(err) => return Err(TestCaseError::fail(format!("failed to parse: {}", err))),
+            };
+            prop_assert_eq!(commit.commit_type, CommitType::from(commit_type));
+            prop_assert_eq!(commit.scope.as_deref(), scope);
+            prop_assert_eq!(commit.summary, summary);
+            prop_assert_eq!(commit.body, body);
+            prop_assert_eq!(commit.is_breaking_change, is_breaking_change);
+            let footers: Vec<&str> = commit
+                .footers
+                .iter()
+                .map(|footer| footer.content.as_str())
+                .collect();
+            prop_assert_eq!(footers, refs.as_deref().into_iter().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn should_round_trip_parsed_messages_through_to_string(
+            message in prop_oneof![message(), conventional_message()]
+        ) {
+            // Arrange
+            let commit = match Commit::parse(&message, None, None) {
+                Ok(commit) => commit.message,
+                Err(_) => return Ok(()),
+            };
+
+            // Act
+            let reparsed = Commit::parse(&commit.to_string(), None, None);
+
+            // Assert
+            match reparsed {
+                Ok(reparsed) => prop_assert_eq!(reparsed.message, commit),
+                Err(err) => return Err(TestCaseError::fail(format!("failed to reparse: {}", err))),
+            }
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::git::error::{Git2Error, TagError};
+use crate::settings::SubjectCase;
 use anyhow::anyhow;
 use colored::Colorize;
 use conventional_commit_parser::error::ParseError;
@@ -20,6 +21,63 @@ pub enum ConventionalCommitError {
         commit_type: String,
         author: String,
     },
+    MissingSignoff {
+        oid: String,
+        summary: String,
+        author: String,
+    },
+    MissingBreakingChangeDescription {
+        oid: String,
+        summary: String,
+        author: String,
+    },
+    EmptyDescription {
+        oid: String,
+        summary: String,
+        author: String,
+    },
+    InvalidScope {
+        oid: String,
+        summary: String,
+        scope: String,
+        author: String,
+    },
+    MissingScope {
+        oid: String,
+        summary: String,
+        commit_type: String,
+        author: String,
+    },
+    ForbiddenScope {
+        oid: String,
+        summary: String,
+        commit_type: String,
+        author: String,
+    },
+    InvalidUtf8 {
+        oid: String,
+        author: String,
+    },
+    InvalidSubjectCase {
+        oid: String,
+        summary: String,
+        case: SubjectCase,
+        author: String,
+    },
+    BodyLineTooLong {
+        oid: String,
+        summary: String,
+        line: usize,
+        length: usize,
+        max: usize,
+        author: String,
+    },
+    DuplicateSubject {
+        oid: String,
+        summary: String,
+        duplicates: Vec<String>,
+        author: String,
+    },
     ParseError(ParseError),
 }
 
@@ -30,6 +88,7 @@ pub enum BumpError {
     SemVerError(semver::Error),
     FmtError(fmt::Error),
     NoCommitFound,
+    NoReleasableChanges,
 }
 
 impl Display for BumpError {
@@ -47,6 +106,14 @@ impl Display for BumpError {
 
 suggestion: Please see https://conventionalcommits.org/en/v1.0.0/#summary for more information.
     Alternatively consider using `cog bump <--version <VERSION>|--auto|--major|--minor>`
+"#
+            ),
+            BumpError::NoReleasableChanges => writeln!(
+                f,
+                r#"cause: No releasable changes since the latest version.
+    Only breaking changes and the `bump.release_commit_types` commits trigger an automatic release.
+
+suggestion: Use `cog bump --auto --force` to release anyway.
 "#
             ),
         }
@@ -77,62 +144,221 @@ impl From<fmt::Error> for BumpError {
     }
 }
 
+impl ConventionalCommitError {
+    /// Stable identifier of the violated rule, e.g. `missing-scope`
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            ConventionalCommitError::CommitFormat { .. } => "commit-format",
+            ConventionalCommitError::CommitTypeNotAllowed { .. } => "commit-type-not-allowed",
+            ConventionalCommitError::MissingSignoff { .. } => "missing-signoff",
+            ConventionalCommitError::MissingBreakingChangeDescription { .. } => {
+                "missing-breaking-change-description"
+            }
+            ConventionalCommitError::EmptyDescription { .. } => "empty-description",
+            ConventionalCommitError::InvalidScope { .. } => "invalid-scope",
+            ConventionalCommitError::MissingScope { .. } => "missing-scope",
+            ConventionalCommitError::ForbiddenScope { .. } => "forbidden-scope",
+            ConventionalCommitError::InvalidUtf8 { .. } => "invalid-utf8",
+            ConventionalCommitError::InvalidSubjectCase { .. } => "invalid-subject-case",
+            ConventionalCommitError::BodyLineTooLong { .. } => "body-line-too-long",
+            ConventionalCommitError::DuplicateSubject { .. } => "duplicate-subject",
+            ConventionalCommitError::ParseError(_) => "parse-error",
+        }
+    }
+
+    /// Id of the errored commit, `None` for a message that is not committed yet
+    pub fn oid(&self) -> Option<&str> {
+        match self {
+            ConventionalCommitError::CommitFormat { oid, .. }
+            | ConventionalCommitError::CommitTypeNotAllowed { oid, .. }
+            | ConventionalCommitError::MissingSignoff { oid, .. }
+            | ConventionalCommitError::MissingBreakingChangeDescription { oid, .. }
+            | ConventionalCommitError::EmptyDescription { oid, .. }
+            | ConventionalCommitError::InvalidScope { oid, .. }
+            | ConventionalCommitError::MissingScope { oid, .. }
+            | ConventionalCommitError::ForbiddenScope { oid, .. }
+            | ConventionalCommitError::InvalidUtf8 { oid, .. }
+            | ConventionalCommitError::InvalidSubjectCase { oid, .. }
+            | ConventionalCommitError::BodyLineTooLong { oid, .. }
+            | ConventionalCommitError::DuplicateSubject { oid, .. } => Some(oid),
+            ConventionalCommitError::ParseError(_) => None,
+        }
+    }
+
+    /// The error without the commit details nor colors, e.g. for machine readable reports
+    pub fn description(&self) -> String {
+        match self {
+            ConventionalCommitError::CommitFormat { cause, .. }
+            | ConventionalCommitError::ParseError(cause) => cause.to_string(),
+            ConventionalCommitError::CommitTypeNotAllowed { commit_type, .. } => {
+                format!("Commit type `{}` not allowed", commit_type)
+            }
+            ConventionalCommitError::MissingSignoff { .. } => {
+                "Missing or malformed `Signed-off-by: Name <email>` trailer".to_string()
+            }
+            ConventionalCommitError::MissingBreakingChangeDescription { .. } => {
+                "Breaking change without a body or `BREAKING CHANGE:` description".to_string()
+            }
+            ConventionalCommitError::EmptyDescription { .. } => {
+                "Commit description is empty".to_string()
+            }
+            ConventionalCommitError::InvalidScope { scope, .. } => format!(
+                "Invalid scope `{}`, only letters, digits and `-_./,*` are allowed",
+                scope
+            ),
+            ConventionalCommitError::MissingScope { commit_type, .. } => {
+                format!("A scope is required for `{}` commits", commit_type)
+            }
+            ConventionalCommitError::ForbiddenScope { commit_type, .. } => {
+                format!("A scope is not allowed for `{}` commits", commit_type)
+            }
+            ConventionalCommitError::InvalidUtf8 { .. } => {
+                "Commit message is not valid UTF-8".to_string()
+            }
+            ConventionalCommitError::InvalidSubjectCase { case, .. } => {
+                let expected = match case {
+                    SubjectCase::Sentence => "an uppercase",
+                    SubjectCase::Lower | SubjectCase::Any => "a lowercase",
+                };
+                format!("Commit description must start with {} letter", expected)
+            }
+            ConventionalCommitError::BodyLineTooLong {
+                line, length, max, ..
+            } => format!(
+                "Commit body line {} is {} characters long, the maximum is {}",
+                line, length, max
+            ),
+            ConventionalCommitError::DuplicateSubject { duplicates, .. } => {
+                let duplicates = duplicates
+                    .iter()
+                    .map(|oid| oid.get(0..7).unwrap_or(oid))
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                format!("Subject duplicated in commits {}", duplicates)
+            }
+        }
+    }
+}
+
 impl Display for ConventionalCommitError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
+        let (oid, author, summary) = match self {
+            ConventionalCommitError::ParseError(err) => {
+                let err = anyhow!(err.clone());
+                return writeln!(f, "{:?}", err);
+            }
+            ConventionalCommitError::InvalidUtf8 { oid, author } => (oid, author, None),
             ConventionalCommitError::CommitFormat {
+                oid,
                 summary,
+                author,
+                ..
+            }
+            | ConventionalCommitError::CommitTypeNotAllowed {
                 oid,
+                summary,
                 author,
-                cause,
-            } => {
-                let error_header = "Errored commit: ".bold().red();
-                let author = format!("<{}>", author).blue();
-                let cause = anyhow!(cause.clone());
-                let cause = format!("{:?}", cause)
-                    .lines()
-                    .collect::<Vec<&str>>()
-                    .join("\n\t");
-
-                writeln!(
-                    f,
-                    "{}{} {}\n\t{message_title}'{summary}'\n\t{cause_title}{}",
-                    error_header,
-                    oid,
-                    author,
-                    cause,
-                    message_title = "Commit message: ".yellow().bold(),
-                    summary = summary.italic(),
-                    cause_title = "Error: ".yellow().bold(),
-                )
-            }
-            ConventionalCommitError::CommitTypeNotAllowed {
+                ..
+            }
+            | ConventionalCommitError::MissingSignoff {
+                oid,
                 summary,
-                commit_type,
+                author,
+            }
+            | ConventionalCommitError::MissingBreakingChangeDescription {
                 oid,
+                summary,
                 author,
-            } => {
-                let error_header = "Errored commit: ".bold().red();
-                let author = format!("<{}>", author).blue();
-                writeln!(
-                    f,
-                    "{}{} {}\n\t{message}'{summary}'\n\t{cause}Commit type `{commit_type}` not allowed",
-                    error_header,
-                    oid,
-                    author,
-                    message = "Commit message:".yellow().bold(),
-                    cause = "Error:".yellow().bold(),
-                    summary = summary.italic(),
-                    commit_type = commit_type.red()
-                )
             }
-            ConventionalCommitError::ParseError(err) => {
-                let err = anyhow!(err.clone());
-                writeln!(f, "{:?}", err)
+            | ConventionalCommitError::EmptyDescription {
+                oid,
+                summary,
+                author,
+            }
+            | ConventionalCommitError::InvalidScope {
+                oid,
+                summary,
+                author,
+                ..
+            }
+            | ConventionalCommitError::MissingScope {
+                oid,
+                summary,
+                author,
+                ..
+            }
+            | ConventionalCommitError::ForbiddenScope {
+                oid,
+                summary,
+                author,
+                ..
+            }
+            | ConventionalCommitError::InvalidSubjectCase {
+                oid,
+                summary,
+                author,
+                ..
             }
+            | ConventionalCommitError::BodyLineTooLong {
+                oid,
+                summary,
+                author,
+                ..
+            }
+            | ConventionalCommitError::DuplicateSubject {
+                oid,
+                summary,
+                author,
+                ..
+            } => (oid, author, Some(summary)),
+        };
+
+        // Parse errors span several lines, with the parser hint
+        let cause = match self {
+            ConventionalCommitError::CommitFormat { cause, .. } => {
+                format!("{:?}", anyhow!(cause.clone()))
+                    .lines()
+                    .collect::<Vec<&str>>()
+                    .join("\n\t")
+            }
+            _ => self.description(),
+        };
+
+        let error_header = "Errored commit: ".bold().red();
+        let author = format!("<{}>", author).blue();
+        write!(f, "{}{} {}", error_header, oid, author)?;
+        if let Some(summary) = summary {
+            let message_title = "Commit message: ".yellow().bold();
+            write!(f, "\n\t{}'{}'", message_title, summary.italic())?;
         }
+        writeln!(f, "\n\t{}{}", "Error: ".yellow().bold(), cause)
     }
 }
 
 impl StdError for ConventionalCommitError {}
 impl StdError for BumpError {}
+
+#[cfg(test)]
+mod test {
+    use crate::conventional::error::ConventionalCommitError;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_display_error_description() {
+        // Arrange
+        let error = ConventionalCommitError::MissingScope {
+            oid: "17f7e23".to_string(),
+            summary: "feat: a feature".to_string(),
+            commit_type: "feat".to_string(),
+            author: "Tom".to_string(),
+        };
+
+        // Act
+        let displayed = error.to_string();
+
+        // Assert
+        assert_that!(displayed).contains("17f7e23");
+        assert_that!(displayed).contains("feat: a feature");
+        assert_that!(displayed).contains(error.description().as_str());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod changelog;
+pub(crate) mod check_cache;
 pub mod commit;
-pub(crate) mod error;
+pub mod error;
 pub mod version;
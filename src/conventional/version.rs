@@ -1,12 +1,13 @@
-use crate::conventional::commit::Commit;
+use crate::conventional::commit::{is_skipped_commit, is_type_listed, Commit};
 use crate::git::repository::Repository;
 use std::fmt;
 
-use crate::conventional::error::BumpError;
+use crate::conventional::error::{BumpError, ConventionalCommitError};
 use crate::git::revspec::RevspecPattern;
+use crate::SETTINGS;
 use colored::*;
 use conventional_commit_parser::commit::CommitType;
-use git2::Commit as Git2Commit;
+use git2::Oid;
 use itertools::Itertools;
 use log::info;
 use semver::Version;
@@ -26,12 +27,25 @@ impl VersionIncrement {
         &self,
         current_version: &Version,
         repository: &Repository,
+    ) -> Result<Version, BumpError> {
+        self.bump_from(current_version, None, repository)
+    }
+
+    /// Same as [`VersionIncrement::bump`], `Auto` looking at the commits after `from`
+    /// instead of the latest tag when it is set.
+    pub(crate) fn bump_from(
+        &self,
+        current_version: &Version,
+        from: Option<Oid>,
+        repository: &Repository,
     ) -> Result<Version, BumpError> {
         match self {
             VersionIncrement::Manual(version) => Version::parse(version).map_err(Into::into),
-            VersionIncrement::Auto => {
-                VersionIncrement::create_version_from_commit_history(current_version, repository)
-            }
+            VersionIncrement::Auto => VersionIncrement::create_version_from_commit_history(
+                current_version,
+                from,
+                repository,
+            ),
             VersionIncrement::Major => Ok(Version::new(current_version.major + 1, 0, 0)),
             VersionIncrement::Patch => Ok(Version::new(
                 current_version.major,
@@ -48,11 +62,14 @@ impl VersionIncrement {
 
     fn create_version_from_commit_history(
         current_version: &Version,
+        from: Option<Oid>,
         repository: &Repository,
     ) -> Result<Version, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_tag_oid()
-            .unwrap_or_else(|_| repository.get_first_commit().unwrap());
+        let changelog_start_oid = from.unwrap_or_else(|| {
+            repository
+                .get_latest_tag_oid()
+                .unwrap_or_else(|_| repository.get_first_commit().unwrap())
+        });
 
         let changelog_start_oid = changelog_start_oid.to_string();
         let changelog_start_oid = Some(changelog_start_oid.as_str());
@@ -64,31 +81,86 @@ impl VersionIncrement {
         let pattern = RevspecPattern::from(pattern);
         let commits = repository.get_commit_range(&pattern)?;
 
-        let commits: Vec<&Git2Commit> = commits
+        let commits: Vec<Result<Commit, _>> = commits
             .commits
             .iter()
-            .filter(|commit| !commit.message().unwrap_or("").starts_with("Merge "))
+            .filter(|commit| !is_skipped_commit(commit))
+            .filter_map(Commit::from_released_commit)
             .collect();
 
         VersionIncrement::display_history(&commits)?;
 
-        let conventional_commits: Vec<Commit> = commits
-            .iter()
-            .map(|commit| Commit::from_git_commit(commit))
-            .filter_map(Result::ok)
-            .collect();
+        let conventional_commits: Vec<Commit> =
+            commits.into_iter().filter_map(Result::ok).collect();
 
-        let increment_type = VersionIncrement::version_increment_from_commit_history(
+        let release_types = &SETTINGS.bump.release_commit_types;
+        if !release_types.is_empty()
+            && !conventional_commits.iter().any(|commit| {
+                commit.message.is_breaking_change || is_type_listed(release_types, &commit.message)
+            })
+        {
+            return Err(BumpError::NoReleasableChanges);
+        }
+
+        let threshold = SETTINGS.bump.patch_to_minor_threshold;
+        let released_fixes = if threshold > 0 {
+            VersionIncrement::count_released_fixes_since_minor(current_version, repository)?
+        } else {
+            0
+        };
+
+        let increment_type = match VersionIncrement::version_increment_from_commit_history(
             current_version,
             &conventional_commits,
-        )?;
+            released_fixes,
+            threshold,
+        ) {
+            // Release types other than features and bug fixes, e.g. `perf`
+            Err(BumpError::NoCommitFound) if !release_types.is_empty() => VersionIncrement::Patch,
+            increment_type => increment_type?,
+        };
 
         increment_type.bump(current_version, repository)
     }
 
+    // Bug fixes already released in patch versions since `major.minor.0`
+    fn count_released_fixes_since_minor(
+        current_version: &Version,
+        repository: &Repository,
+    ) -> Result<usize, BumpError> {
+        if current_version.patch == 0 {
+            return Ok(0);
+        }
+
+        let minor_version = Version::new(current_version.major, current_version.minor, 0);
+        let tags = repository.all_tags()?;
+        let minor_tag = tags
+            .iter()
+            .find(|tag| tag.to_version().ok().as_ref() == Some(&minor_version));
+        let latest_tag = repository.get_latest_tag()?;
+
+        let (minor_tag, latest_tag) = match (minor_tag, latest_tag.oid()) {
+            (Some(minor_tag), Some(latest_oid)) => (minor_tag.oid_unchecked(), latest_oid),
+            _ => return Ok(0),
+        };
+
+        let pattern = format!("{}..{}", minor_tag, latest_tag);
+        let commits = repository.get_commit_range(&RevspecPattern::from(pattern.as_str()))?;
+
+        Ok(commits
+            .commits
+            .iter()
+            .filter(|commit| !is_skipped_commit(commit))
+            .filter_map(|commit| Commit::from_git_commit(commit).ok())
+            .filter(|commit| commit.message.commit_type == CommitType::BugFix)
+            .count())
+    }
+
     fn version_increment_from_commit_history(
         current_version: &Version,
         commits: &[Commit],
+        released_fixes: usize,
+        patch_to_minor_threshold: usize,
     ) -> Result<VersionIncrement, BumpError> {
         let is_major_bump = || {
             current_version.major != 0
@@ -97,16 +169,22 @@ impl VersionIncrement {
                     .any(|commit| commit.message.is_breaking_change)
         };
 
+        let fixes = commits
+            .iter()
+            .filter(|commit| commit.message.commit_type == CommitType::BugFix)
+            .count();
+
+        let is_patch_bump = || fixes > 0;
+
         let is_minor_bump = || {
-            commits
-                .iter()
-                .any(|commit| commit.message.commit_type == CommitType::Feature)
-        };
+            let reached_threshold = patch_to_minor_threshold > 0
+                && is_patch_bump()
+                && released_fixes + fixes >= patch_to_minor_threshold;
 
-        let is_patch_bump = || {
-            commits
-                .iter()
-                .any(|commit| commit.message.commit_type == CommitType::BugFix)
+            reached_threshold
+                || commits
+                    .iter()
+                    .any(|commit| commit.message.commit_type == CommitType::Feature)
         };
 
         if is_major_bump() {
@@ -120,12 +198,9 @@ impl VersionIncrement {
         }
     }
 
-    fn display_history(commits: &[&Git2Commit]) -> Result<(), fmt::Error> {
-        let conventional_commits: Vec<Result<_, _>> = commits
-            .iter()
-            .map(|commit| Commit::from_git_commit(commit))
-            .collect();
-
+    fn display_history(
+        conventional_commits: &[Result<Commit, Box<ConventionalCommitError>>],
+    ) -> Result<(), fmt::Error> {
         // Commits which type are neither feat, fix nor breaking changes
         // won't affect the version number.
         let mut non_bump_commits: Vec<&CommitType> = conventional_commits
@@ -218,7 +293,11 @@ mod test {
                     footers: vec![],
                 },
                 author: "".to_string(),
+                author_email: None,
                 date: Utc::now().naive_local(),
+                gitmoji: None,
+                ticket: None,
+                pull_request: None,
             }
         }
     }
@@ -325,6 +404,8 @@ mod test {
         let version = VersionIncrement::version_increment_from_commit_history(
             &Version::parse("1.0.0")?,
             &[patch],
+            0,
+            0,
         );
 
         // Assert
@@ -345,6 +426,8 @@ mod test {
         let version = VersionIncrement::version_increment_from_commit_history(
             &Version::parse("1.0.0")?,
             &[breaking_change, feature],
+            0,
+            0,
         );
 
         // Assert
@@ -365,6 +448,8 @@ mod test {
         let version = VersionIncrement::version_increment_from_commit_history(
             &Version::parse("0.1.0")?,
             &[breaking_change, feature],
+            0,
+            0,
         );
 
         // Assert
@@ -385,6 +470,8 @@ mod test {
         let version = VersionIncrement::version_increment_from_commit_history(
             &Version::parse("1.0.0")?,
             &[patch, feature],
+            0,
+            0,
         );
 
         // Assert
@@ -405,6 +492,8 @@ mod test {
         let version = VersionIncrement::version_increment_from_commit_history(
             &Version::parse("1.0.0")?,
             &[patch, feature],
+            0,
+            0,
         );
 
         let result = version.unwrap_err().to_string();
@@ -426,4 +515,48 @@ suggestion: Please see https://conventionalcommits.org/en/v1.0.0/#summary for mo
 
         Ok(())
     }
+
+    #[test]
+    fn should_promote_patch_to_minor_at_threshold() -> Result<()> {
+        // Arrange
+        let patch = Commit::commit_fixture(CommitType::BugFix, false);
+        let other_patch = Commit::commit_fixture(CommitType::BugFix, false);
+
+        // Act
+        let version = VersionIncrement::version_increment_from_commit_history(
+            &Version::parse("1.0.1")?,
+            &[patch, other_patch],
+            1,
+            3,
+        );
+
+        // Assert
+        assert_that!(version)
+            .is_ok()
+            .is_equal_to(VersionIncrement::Minor);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_promote_patch_to_minor_below_threshold() -> Result<()> {
+        // Arrange
+        let patch = Commit::commit_fixture(CommitType::BugFix, false);
+        let other_patch = Commit::commit_fixture(CommitType::BugFix, false);
+
+        // Act
+        let version = VersionIncrement::version_increment_from_commit_history(
+            &Version::parse("1.0.0")?,
+            &[patch, other_patch],
+            0,
+            3,
+        );
+
+        // Assert
+        assert_that!(version)
+            .is_ok()
+            .is_equal_to(VersionIncrement::Patch);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,207 @@
+//! Readiness checks behind `cog doctor`.
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+
+use crate::git::hook::{PRE_COMMIT_HOOK_PATH, PRE_PUSH_HOOK_PATH};
+use crate::git::repository::Repository;
+use crate::settings::Settings;
+use crate::CONFIG_PATH;
+
+/// The outcome of a single readiness check.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub name: &'static str,
+    pub passed: bool,
+    /// Critical checks make cog unusable when they fail, the others only degrade it
+    pub critical: bool,
+    pub detail: String,
+    /// How to fix a failed check
+    pub hint: Option<String>,
+}
+
+impl Diagnostic {
+    fn pass(name: &'static str, critical: bool, detail: impl Into<String>) -> Self {
+        Diagnostic {
+            name,
+            passed: true,
+            critical,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn fail(
+        name: &'static str,
+        critical: bool,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Diagnostic {
+            name,
+            passed: false,
+            critical,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.detail)?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n    hint: {}", hint)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Run every readiness check against the repository containing `path`.
+///
+/// Checks depending on a git repository are skipped when none is found.
+pub fn diagnose<S: AsRef<Path> + ?Sized>(path: &S) -> Vec<Diagnostic> {
+    let repository = match Repository::open(path) {
+        Ok(repository) => repository,
+        Err(_) => {
+            return vec![Diagnostic::fail(
+                "git repository",
+                true,
+                "not a git repository",
+                "run `git init` or move into an existing repository",
+            )]
+        }
+    };
+
+    let repo_dir = repository.get_repo_dir().unwrap_or_else(|| path.as_ref());
+
+    vec![
+        Diagnostic::pass("git repository", true, format!("found in {:?}", repo_dir)),
+        check_config(&repo_dir.join(CONFIG_PATH)),
+        check_head(&repository),
+        check_tags(&repository),
+        check_hooks(repo_dir),
+    ]
+}
+
+fn check_config(path: &Path) -> Diagnostic {
+    const NAME: &str = "config";
+
+    if !path.exists() {
+        return Diagnostic::fail(
+            NAME,
+            false,
+            format!("{} not found, using the default config", CONFIG_PATH),
+            "run `cog init` to create one",
+        );
+    }
+
+    match Settings::validate_file(path) {
+        Ok(problems) if problems.is_empty() => {
+            Diagnostic::pass(NAME, true, format!("{} is valid", CONFIG_PATH))
+        }
+        Ok(problems) => Diagnostic::fail(
+            NAME,
+            true,
+            format!("{} problem(s) found in {}", problems.len(), CONFIG_PATH),
+            "run `cog validate-config` for details",
+        ),
+        Err(err) => Diagnostic::fail(
+            NAME,
+            true,
+            format!("failed to read {}: {}", CONFIG_PATH, err),
+            "check the file permissions",
+        ),
+    }
+}
+
+fn check_head(repository: &Repository) -> Diagnostic {
+    const NAME: &str = "HEAD";
+
+    match repository.get_head_commit() {
+        Ok(commit) => Diagnostic::pass(NAME, true, format!("points to {}", commit.id())),
+        Err(_) => Diagnostic::fail(
+            NAME,
+            true,
+            "HEAD does not point to a commit",
+            "create a first commit, e.g. `cog commit chore \"initial commit\"`",
+        ),
+    }
+}
+
+fn check_tags(repository: &Repository) -> Diagnostic {
+    const NAME: &str = "tags";
+
+    match repository.get_latest_tag() {
+        Ok(tag) => Diagnostic::pass(NAME, false, format!("latest tag is {}", tag)),
+        Err(_) => Diagnostic::fail(
+            NAME,
+            false,
+            "no tag found, versions will start from 0.0.0",
+            "tag the current version, e.g. `git tag 0.1.0`, or run `cog bump --auto`",
+        ),
+    }
+}
+
+fn check_hooks(repo_dir: &Path) -> Diagnostic {
+    const NAME: &str = "git hooks";
+
+    let missing: Vec<&str> = [PRE_COMMIT_HOOK_PATH, PRE_PUSH_HOOK_PATH]
+        .into_iter()
+        .filter(|hook| !repo_dir.join(hook).exists())
+        .collect();
+
+    if missing.is_empty() {
+        Diagnostic::pass(NAME, false, "commit-msg and pre-push hooks are installed")
+    } else {
+        Diagnostic::fail(
+            NAME,
+            false,
+            format!("missing {}", missing.join(", ")),
+            "run `cog install-hook all`",
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::doctor::diagnose;
+    use crate::git::hook::HookKind;
+    use crate::CocoGitto;
+
+    use anyhow::Result;
+    use cmd_lib::run_cmd;
+    use sealed_test::prelude::*;
+    use speculoos::prelude::*;
+
+    #[sealed_test]
+    fn diagnose_outside_of_a_repository() {
+        // Act
+        let diagnostics = diagnose(".");
+
+        // Assert
+        assert_that!(diagnostics).has_length(1);
+        assert_that!(diagnostics[0].passed).is_false();
+        assert_that!(diagnostics[0].critical).is_true();
+    }
+
+    #[sealed_test]
+    fn diagnose_ready_repository() -> Result<()> {
+        // Arrange
+        run_cmd!(
+            git init;
+            echo "" > cog.toml;
+            git add .;
+            git commit -m "chore: init";
+            git tag 1.0.0;
+        )?;
+        CocoGitto::get()?.install_hook(HookKind::All)?;
+
+        // Act
+        let diagnostics = diagnose(".");
+
+        // Assert
+        assert_that!(diagnostics.iter().all(|diagnostic| diagnostic.passed)).is_true();
+        Ok(())
+    }
+}
@@ -5,8 +5,9 @@ use crate::git::oid::OidOf;
 use crate::conventional::error::ConventionalCommitError;
 use colored::*;
 
+/// The non compliant commits found by [`crate::CocoGitto::check`]
 #[derive(Debug)]
-pub(crate) struct CogCheckReport {
+pub struct CogCheckReport {
     pub from: OidOf,
     pub errors: Vec<ConventionalCommitError>,
 }
@@ -32,6 +33,67 @@ impl Display for CogCheckReport {
     }
 }
 
+impl std::error::Error for CogCheckReport {}
+
+impl CogCheckReport {
+    /// This report as a SARIF 2.1.0 log, see [`sarif_log`]
+    pub fn to_sarif(&self) -> serde_json::Value {
+        sarif_log(&self.errors)
+    }
+}
+
+/// A SARIF 2.1.0 log with a result per non compliant commit, located by its commit id,
+/// for code scanning tools. Code scanning tools require a physical location, so the commit
+/// id is also used as the artifact uri, with the commit message first line as region.
+pub fn sarif_log(errors: &[ConventionalCommitError]) -> serde_json::Value {
+    let mut rules: Vec<&str> = errors
+        .iter()
+        .map(ConventionalCommitError::rule_id)
+        .collect();
+    rules.sort_unstable();
+    rules.dedup();
+
+    let rules: Vec<serde_json::Value> = rules
+        .into_iter()
+        .map(|rule| serde_json::json!({ "id": rule }))
+        .collect();
+
+    let results: Vec<serde_json::Value> = errors
+        .iter()
+        .map(|err| {
+            let commit = err.oid().unwrap_or("not committed");
+            serde_json::json!({
+                "ruleId": err.rule_id(),
+                "level": "error",
+                "message": { "text": format!("{}: {}", commit, err.description()) },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": commit },
+                        "region": { "startLine": 1 }
+                    },
+                    "logicalLocations": [{ "name": commit, "kind": "commit" }]
+                }],
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "cocogitto",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/cocogitto/cocogitto",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
 // This is not meant to be unwrapped like other errors
 // just to emit a warning on hook failure
 pub(crate) struct PreHookError {
@@ -1,21 +1,22 @@
 use crate::git::error::Git2Error;
 use crate::git::repository::Repository;
-use git2::{Commit, ObjectType, Oid, ResetType, Signature, Tree};
+use git2::{BranchType, Commit, ObjectType, Oid, ResetType, Signature, Sort, Tree};
+use std::collections::HashMap;
 use std::io::Write;
 use std::process::{Command, Stdio};
 
 impl Repository {
     pub(crate) fn commit(&self, message: &str, sign: bool) -> Result<Oid, Git2Error> {
-        let sig = self.0.signature()?;
-        let tree_id = self.0.index()?.write_tree()?;
-        let tree = self.0.find_tree(tree_id)?;
-        let is_empty = self.0.head().is_err();
+        let sig = self.git.signature()?;
+        let tree_id = self.git.index()?.write_tree()?;
+        let tree = self.git.find_tree(tree_id)?;
+        let is_empty = self.git.head().is_err();
         let has_delta = self.get_diff(false).is_some();
 
         if !is_empty && has_delta {
-            let head = &self.0.head()?;
+            let head = &self.git.head()?;
             let head_target = head.target().expect("Cannot get HEAD target");
-            let tip = &self.0.find_commit(head_target)?;
+            let tip = &self.git.find_commit(head_target)?;
 
             self.commit_or_signed_commit(&sig, message, &tree, &[tip], sign)
                 .map_err(Git2Error::from)
@@ -35,6 +36,125 @@ impl Repository {
         }
     }
 
+    /// Amend HEAD with the current index content, keeping its message and author.
+    pub(crate) fn amend_head(&self) -> Result<Oid, Git2Error> {
+        let head = self.get_head_commit()?;
+        let tree_id = self.git.index()?.write_tree()?;
+        let tree = self.git.find_tree(tree_id)?;
+
+        head.amend(Some("HEAD"), None, None, None, None, Some(&tree))
+            .map_err(Git2Error::Other)
+    }
+
+    /// Fails if HEAD is reachable from any remote tracking branch.
+    pub(crate) fn ensure_head_not_pushed(&self) -> Result<(), Git2Error> {
+        let head = self.get_head_commit_oid()?;
+        self.ensure_not_pushed(head)
+    }
+
+    /// Fails if `oid` is reachable from any remote tracking branch.
+    pub(crate) fn ensure_not_pushed(&self, oid: Oid) -> Result<(), Git2Error> {
+        for branch in self.git.branches(Some(BranchType::Remote))? {
+            let (branch, _) = branch?;
+            let target = match branch.get().target() {
+                Some(target) => target,
+                None => continue,
+            };
+
+            if target == oid || self.git.graph_descendant_of(target, oid)? {
+                return Err(Git2Error::CommitAlreadyPushed {
+                    oid: oid.to_string(),
+                    remote_branch: branch.name()?.unwrap_or_default().to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replace the message of `oid`, an ancestor of HEAD, and rebase its descendants
+    /// on the reworded commit. Trees, authors and committers are kept as is, so the
+    /// rebase cannot conflict and merge commits are preserved. Returns the new HEAD.
+    pub(crate) fn reword_commit(&self, oid: Oid, message: &str) -> Result<Oid, Git2Error> {
+        self.reword_commits(&HashMap::from([(oid, message.to_string())]))
+    }
+
+    /// Same as [`Repository::reword_commit`] for several ancestors of HEAD at once,
+    /// `messages` mapping each commit to reword to its new message.
+    pub(crate) fn reword_commits(&self, messages: &HashMap<Oid, String>) -> Result<Oid, Git2Error> {
+        let head = self.get_head_commit_oid()?;
+        let mut revwalk = self.git.revwalk()?;
+        revwalk.push(head)?;
+        // Only walk from the oldest commits to reword, the others descend from them
+        for oid in messages.keys() {
+            let mut is_descendant = false;
+            for other in messages.keys() {
+                is_descendant |= self.git.graph_descendant_of(*oid, *other)?;
+            }
+
+            if !is_descendant {
+                for parent in self.git.find_commit(*oid)?.parent_ids() {
+                    revwalk.hide(parent)?;
+                }
+            }
+        }
+        revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+
+        let mut rewritten = HashMap::new();
+        for commit in revwalk {
+            let commit = self.git.find_commit(commit?)?;
+            let message = match messages.get(&commit.id()) {
+                Some(message) => message.to_string(),
+                None if commit.parent_ids().any(|id| rewritten.contains_key(&id)) => {
+                    String::from_utf8_lossy(commit.message_bytes()).to_string()
+                }
+                None => continue,
+            };
+
+            let parents = commit
+                .parent_ids()
+                .map(|id| rewritten.get(&id).copied().unwrap_or(id))
+                .collect();
+            let new_oid = self.recreate_commit(&commit, &message, parents)?;
+            rewritten.insert(commit.id(), new_oid);
+        }
+
+        let new_head = rewritten.get(&head).copied().unwrap_or(head);
+        let mut head_ref = self.git.head()?;
+        if head_ref.is_branch() {
+            let oids = messages.keys().map(Oid::to_string).collect::<Vec<_>>();
+            head_ref.set_target(new_head, &format!("cog reword: {}", oids.join(", ")))?;
+        } else {
+            self.git.set_head_detached(new_head)?;
+        }
+
+        Ok(new_head)
+    }
+
+    fn recreate_commit(
+        &self,
+        commit: &Commit,
+        message: &str,
+        parents: Vec<Oid>,
+    ) -> Result<Oid, Git2Error> {
+        let parents = parents
+            .into_iter()
+            .map(|parent| self.git.find_commit(parent))
+            .collect::<Result<Vec<_>, _>>()?;
+        let parents: Vec<&Commit> = parents.iter().collect();
+
+        self.git
+            .commit(
+                None,
+                &commit.author(),
+                &commit.committer(),
+                message,
+                &commit.tree()?,
+                &parents,
+            )
+            .map_err(Git2Error::from)
+    }
+
     fn commit_or_signed_commit(
         &self,
         sig: &Signature,
@@ -45,13 +165,13 @@ impl Repository {
     ) -> Result<Oid, Git2Error> {
         if !sign {
             return self
-                .0
+                .git
                 .commit(Some("HEAD"), sig, sig, commit_message, tree, parents)
                 .map_err(Git2Error::Other);
         }
 
         let commit_buf = self
-            .0
+            .git
             .commit_create_buffer(sig, sig, commit_message, tree, parents)?;
 
         let commit_as_str = std::str::from_utf8(&commit_buf)
@@ -61,12 +181,12 @@ impl Repository {
         let key = self.signin_key().ok();
         let gpg_signature = gpg_sign_string(key, &commit_as_str)?;
         let oid = self
-            .0
+            .git
             .commit_signed(&commit_as_str, &gpg_signature, Some("gpgsig"))?;
 
         // This is needed because git2 does not update HEAD after creating a signed commit
-        let commit = self.0.find_object(oid, Some(ObjectType::Commit))?;
-        self.0.reset(&commit, ResetType::Mixed, None)?;
+        let commit = self.git.find_object(oid, Some(ObjectType::Commit))?;
+        self.git.reset(&commit, ResetType::Mixed, None)?;
         Ok(oid).map_err(Git2Error::Other)
     }
 }
@@ -8,10 +8,10 @@ impl Repository {
 
         let diff = match &self.get_head() {
             Some(head) => self
-                .0
+                .git
                 .diff_tree_to_index(head.as_tree(), None, Some(&mut options)),
             None => self
-                .0
+                .git
                 .diff_tree_to_workdir_with_index(None, Some(&mut options)),
         };
 
@@ -25,6 +25,10 @@ pub enum Git2Error {
     Other(git2::Error),
     NoTagFound,
     CommitterNotFound,
+    CommitAlreadyPushed {
+        oid: String,
+        remote_branch: String,
+    },
 }
 
 #[derive(Debug)]
@@ -37,6 +41,10 @@ pub enum TagError {
         prefix: String,
         tag: String,
     },
+    PatternMismatch {
+        pattern: String,
+        tag: String,
+    },
     NotFound {
         tag: String,
         err: git2::Error,
@@ -117,6 +125,11 @@ impl Display for Git2Error {
             ),
             Git2Error::IOError(_) => writeln!(f, "IO Error"),
             Git2Error::GpgError(_) => writeln!(f, "failed to sign commit"),
+            Git2Error::CommitAlreadyPushed { oid, remote_branch } => writeln!(
+                f,
+                "cannot rewrite commit {}, it has already been pushed to {}",
+                oid, remote_branch
+            ),
         }?;
 
         match self {
@@ -146,6 +159,9 @@ impl Display for TagError {
             TagError::InvalidPrefixError { prefix, tag } => {
                 writeln!(f, "Expected a tag with prefix {}, got {}", prefix, tag)
             }
+            TagError::PatternMismatch { pattern, tag } => {
+                writeln!(f, "tag {} does not match the tag pattern {}", tag, pattern)
+            }
             TagError::NotFound { tag, err } => {
                 writeln!(f, "tag {} not found", tag)?;
                 writeln!(f, "\tcause: {}", err)
@@ -10,12 +10,16 @@ use anyhow::{anyhow, Result};
 
 pub(crate) static PRE_PUSH_HOOK: &[u8] = include_bytes!("assets/pre-push");
 pub(crate) static PREPARE_COMMIT_HOOK: &[u8] = include_bytes!("assets/commit-msg");
-const PRE_COMMIT_HOOK_PATH: &str = ".git/hooks/commit-msg";
-const PRE_PUSH_HOOK_PATH: &str = ".git/hooks/pre-push";
+pub(crate) static PREPARE_COMMIT_MSG_HOOK: &[u8] = include_bytes!("assets/prepare-commit-msg");
+pub(crate) const PRE_COMMIT_HOOK_PATH: &str = ".git/hooks/commit-msg";
+pub(crate) const PRE_PUSH_HOOK_PATH: &str = ".git/hooks/pre-push";
+pub(crate) const PREPARE_COMMIT_MSG_HOOK_PATH: &str = ".git/hooks/prepare-commit-msg";
 
 pub enum HookKind {
     PrepareCommit,
     PrePush,
+    /// Prefill the message of a bare `git commit` with a conventional commit template
+    PrepareCommitMsg,
     All,
 }
 
@@ -30,6 +34,7 @@ impl CocoGitto {
         match kind {
             HookKind::PrepareCommit => create_hook(repodir, HookKind::PrepareCommit)?,
             HookKind::PrePush => create_hook(repodir, HookKind::PrePush)?,
+            HookKind::PrepareCommitMsg => create_hook(repodir, HookKind::PrepareCommitMsg)?,
             HookKind::All => {
                 create_hook(repodir, HookKind::PrepareCommit)?;
                 create_hook(repodir, HookKind::PrePush)?
@@ -44,6 +49,10 @@ fn create_hook(path: &Path, kind: HookKind) -> io::Result<()> {
     let (hook_path, hook_content) = match kind {
         HookKind::PrepareCommit => (path.join(PRE_COMMIT_HOOK_PATH), PREPARE_COMMIT_HOOK),
         HookKind::PrePush => (path.join(PRE_PUSH_HOOK_PATH), PRE_PUSH_HOOK),
+        HookKind::PrepareCommitMsg => (
+            path.join(PREPARE_COMMIT_MSG_HOOK_PATH),
+            PREPARE_COMMIT_MSG_HOOK,
+        ),
         HookKind::All => unreachable!(),
     };
 
@@ -103,6 +112,57 @@ mod tests {
         Ok(())
     }
 
+    #[sealed_test]
+    fn add_prepare_commit_msg_hook() -> Result<()> {
+        // Arrange
+        run_cmd!(git init)?;
+
+        let cog = CocoGitto::get()?;
+
+        // Act
+        cog.install_hook(HookKind::PrepareCommitMsg)?;
+
+        // Assert
+        let hook = std::fs::read_to_string(".git/hooks/prepare-commit-msg")?;
+        assert_that!(hook).starts_with("#!/bin/sh");
+        assert_that!(hook).contains("if [ -n \"$COMMIT_SOURCE\" ]; then\n    exit 0");
+        assert_that!(hook).contains("# <type>[(scope)][!]: <description>");
+        assert_that!(Path::new(".git/hooks/commit-msg")).does_not_exist();
+        Ok(())
+    }
+
+    #[sealed_test]
+    #[cfg(target_family = "unix")]
+    fn prepare_commit_msg_hook_skips_supplied_messages() -> Result<()> {
+        // Arrange
+        run_cmd!(git init)?;
+        let cog = CocoGitto::get()?;
+        cog.install_hook(HookKind::PrepareCommitMsg)?;
+        let run_hook = |source: &[&str]| -> Result<String> {
+            std::fs::write("MSG", "existing message\n")?;
+            std::process::Command::new(".git/hooks/prepare-commit-msg")
+                .arg("MSG")
+                .args(source)
+                .status()?;
+            Ok(std::fs::read_to_string("MSG")?)
+        };
+
+        // Act
+        let bare = run_hook(&[])?;
+        let skipped: Vec<String> = ["message", "template", "merge", "squash", "commit"]
+            .iter()
+            .map(|source| run_hook(&[source, "HEAD"]))
+            .collect::<Result<_>>()?;
+
+        // Assert
+        assert_that!(bare).starts_with("# <type>[(scope)][!]: <description>\n");
+        assert_that!(bare).ends_with("\nexisting message\n");
+        for message in skipped {
+            assert_that!(message).is_equal_to("existing message\n".to_string());
+        }
+        Ok(())
+    }
+
     #[sealed_test]
     fn add_all() -> Result<()> {
         // Arrange
@@ -3,8 +3,10 @@ pub mod diff;
 pub(crate) mod error;
 pub mod hook;
 pub mod oid;
+pub(crate) mod path;
 pub mod repository;
 pub mod revspec;
 pub mod stash;
+pub(crate) mod stats;
 pub mod status;
 pub mod tag;
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use git2::{Commit, ErrorCode, Oid};
+
+use crate::git::error::Git2Error;
+
+/// Select the commits modifying a file or a directory of the repository.
+///
+/// Instead of diffing each commit against its parent, the id of the tree entry at `path`
+/// is compared: git objects are content addressed, so the entry id only changes when a
+/// file under `path` does. Entry ids are cached by commit since each commit tree is also
+/// looked up as the parent of the next one.
+pub(crate) struct PathFilter {
+    path: PathBuf,
+    entries: HashMap<Oid, Option<Oid>>,
+}
+
+impl PathFilter {
+    pub(crate) fn new(path: &Path) -> Self {
+        // Tree lookups expect a plain relative path, e.g. `./packages/api/` -> `packages/api`
+        let path = path
+            .components()
+            .filter(|component| matches!(component, Component::Normal(_)))
+            .collect();
+
+        PathFilter {
+            path,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Whether `commit` modifies `path` compared to its first parent. Root commits
+    /// modify `path` if it exists in their tree.
+    pub(crate) fn touches(&mut self, commit: &Commit) -> Result<bool, Git2Error> {
+        if self.path.as_os_str().is_empty() {
+            return Ok(true);
+        }
+
+        let entry = self.entry(commit)?;
+        let parent_entry = match commit.parent(0) {
+            Ok(parent) => self.entry(&parent)?,
+            Err(_) => None,
+        };
+
+        Ok(entry != parent_entry)
+    }
+
+    fn entry(&mut self, commit: &Commit) -> Result<Option<Oid>, Git2Error> {
+        if let Some(entry) = self.entries.get(&commit.id()) {
+            return Ok(*entry);
+        }
+
+        let entry = match commit.tree()?.get_path(&self.path) {
+            Ok(entry) => Some(entry.id()),
+            Err(err) if err.code() == ErrorCode::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        self.entries.insert(commit.id(), entry);
+        Ok(entry)
+    }
+}
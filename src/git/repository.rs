@@ -6,36 +6,55 @@ use git2::{
     Commit as Git2Commit, IndexAddOption, Object, ObjectType, Oid, Repository as Git2Repository,
 };
 
-pub(crate) struct Repository(pub(crate) Git2Repository);
+pub(crate) struct Repository {
+    pub(crate) git: Git2Repository,
+    /// The package version tags are scoped to when `bump.tag_format` contains `{scope}`
+    tag_scope: Option<String>,
+}
 
 impl Repository {
     pub(crate) fn signin_key(&self) -> Result<String, Git2Error> {
-        let config = self.0.config()?;
+        let config = self.git.config()?;
         config.get_string("user.signingKey").map_err(Into::into)
     }
 
     pub(crate) fn gpg_sign(&self) -> bool {
-        let config = self.0.config().expect("failed to retrieve gitconfig");
+        let config = self.git.config().expect("failed to retrieve gitconfig");
         config.get_bool("commit.gpgSign").unwrap_or(false)
     }
 
     pub(crate) fn init<S: AsRef<Path> + ?Sized>(path: &S) -> Result<Repository, Git2Error> {
         let repository =
             Git2Repository::init(path).map_err(Git2Error::FailedToInitializeRepository)?;
-        Ok(Repository(repository))
+        Ok(Repository {
+            git: repository,
+            tag_scope: None,
+        })
     }
 
     pub(crate) fn open<S: AsRef<Path> + ?Sized>(path: &S) -> Result<Repository, Git2Error> {
         let repo = Git2Repository::discover(path).map_err(Git2Error::FailedToOpenRepository)?;
-        Ok(Repository(repo))
+        Ok(Repository {
+            git: repo,
+            tag_scope: None,
+        })
+    }
+
+    /// Only consider the version tags of the package `scope`, see `bump.tag_format`
+    pub(crate) fn set_tag_scope(&mut self, scope: &str) {
+        self.tag_scope = Some(scope.to_string());
+    }
+
+    pub(crate) fn tag_scope(&self) -> Option<&str> {
+        self.tag_scope.as_deref()
     }
 
     pub(crate) fn get_repo_dir(&self) -> Option<&Path> {
-        self.0.workdir()
+        self.git.workdir()
     }
 
     pub(crate) fn add_all(&self) -> Result<(), Git2Error> {
-        let mut index = self.0.index()?;
+        let mut index = self.git.index()?;
         index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
         index.write().map_err(Git2Error::GitAddError)
     }
@@ -45,7 +64,7 @@ impl Repository {
     }
 
     pub(crate) fn get_head_commit(&self) -> Result<Git2Commit, Git2Error> {
-        let head_ref = self.0.head();
+        let head_ref = self.git.head();
         match head_ref {
             Ok(head) => head.peel_to_commit().map_err(Git2Error::PeelToCommitError),
             Err(err) => Err(Git2Error::UnableToGetHead(err)),
@@ -53,7 +72,7 @@ impl Repository {
     }
 
     pub(crate) fn get_first_commit(&self) -> Result<Oid, Git2Error> {
-        let mut revwalk = self.0.revwalk()?;
+        let mut revwalk = self.git.revwalk()?;
         revwalk.push_head()?;
         revwalk
             .last()
@@ -62,20 +81,30 @@ impl Repository {
     }
 
     pub(crate) fn get_head(&self) -> Option<Object> {
-        Repository::tree_to_treeish(&self.0, Some(&"HEAD".to_string()))
+        Repository::tree_to_treeish(&self.git, Some(&"HEAD".to_string()))
             .ok()
             .flatten()
     }
 
     pub(crate) fn get_branch_shorthand(&self) -> Option<String> {
-        self.0
+        self.git
             .head()
             .ok()
             .and_then(|head| head.shorthand().map(|shorthand| shorthand.to_string()))
     }
 
+    /// The branch `origin/HEAD` points to, e.g. `main` for `refs/remotes/origin/main`
+    pub(crate) fn detect_default_branch(&self) -> Option<String> {
+        let origin_head = self.git.find_reference("refs/remotes/origin/HEAD").ok()?;
+        let target = origin_head.symbolic_target()?;
+
+        target
+            .strip_prefix("refs/remotes/origin/")
+            .map(str::to_string)
+    }
+
     pub(crate) fn get_author(&self) -> Result<String, Git2Error> {
-        self.0
+        self.git
             .signature()?
             .name()
             .map(|name| name.to_string())
@@ -98,7 +127,7 @@ impl Repository {
 
 impl Debug for Repository {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Repository {{ 0: {:?}}}", self.0.path())
+        writeln!(f, "Repository {{ 0: {:?}}}", self.git.path())
     }
 }
 
@@ -1,7 +1,7 @@
 use std::fmt;
 use std::fmt::Formatter;
 
-use git2::{Commit, ErrorCode, Oid};
+use git2::{Commit, ErrorCode, Oid, Sort};
 
 use crate::conventional::changelog::release::Release;
 use crate::git::error::Git2Error;
@@ -66,14 +66,20 @@ impl From<(&str, &str)> for RevspecPattern {
 impl Repository {
     /// Return a [`CommitRange`] containing all commit in the current repository
     pub fn all_commits(&self) -> Result<CommitRange, Git2Error> {
-        let mut revwalk = self.0.revwalk()?;
+        self.all_commits_sorted(Sort::NONE)
+    }
+
+    /// Same as [`Repository::all_commits`], walking the history in the given `sort` order
+    pub fn all_commits_sorted(&self, sort: Sort) -> Result<CommitRange<'_>, Git2Error> {
+        let mut revwalk = self.git.revwalk()?;
+        revwalk.set_sorting(sort)?;
         revwalk.push_head()?;
         let mut commits = vec![];
 
         for oid in revwalk {
             match oid {
                 Ok(oid) => {
-                    let commit = self.0.find_commit(oid)?;
+                    let commit = self.git.find_commit(oid)?;
                     commits.push(commit)
                 }
                 Err(e) if e.code() == ErrorCode::NotFound => {
@@ -98,7 +104,22 @@ impl Repository {
         Ok(CommitRange { from, to, commits })
     }
 
-    pub(crate) fn get_release_range(&self, pattern: RevspecPattern) -> Result<Release, Git2Error> {
+    /// Count the commits reachable from `to` but not from `from`,
+    /// or all the commits reachable from `to` if `from` is `None`
+    pub(crate) fn count_commits(&self, from: Option<&Oid>, to: &Oid) -> Result<usize, Git2Error> {
+        let mut revwalk = self.git.revwalk()?;
+        let to = self.git.find_object(*to, None)?.peel_to_commit()?;
+        revwalk.push(to.id())?;
+
+        if let Some(from) = from {
+            let from = self.git.find_object(*from, None)?.peel_to_commit()?;
+            revwalk.hide(from.id())?;
+        }
+
+        Ok(revwalk.count())
+    }
+
+    pub(crate) fn get_release_range(&self, pattern: RevspecPattern) -> anyhow::Result<Release<'_>> {
         let target = if let Some(target) = pattern.from {
             self.resolve_oid_of(&target)
         } else {
@@ -111,7 +132,7 @@ impl Repository {
         };
 
         let range = self.get_commit_range(&pattern)?;
-        let release = Release::from(range);
+        let release = Release::try_from(range)?;
 
         let mut release = if !release.contains_oid(target.oid()) {
             self.populate_previous_release(release, target.oid())?
@@ -128,7 +149,7 @@ impl Repository {
         &'a self,
         mut release: Release<'a>,
         target: &Oid,
-    ) -> Result<Release<'a>, Git2Error> {
+    ) -> anyhow::Result<Release<'a>> {
         let pattern = format!("..{}", release.from);
         let pattern = RevspecPattern::from(pattern.as_str());
         let range = self.get_commit_range(&pattern)?;
@@ -142,14 +163,14 @@ impl Repository {
         // We have reached the `from` target commit
         else if target_in_range {
             if range.from != range.to {
-                let previous = Release::from(range);
+                let previous = Release::try_from(range)?;
                 release.previous = Some(Box::new(previous));
             }
 
             return Ok(release);
         }
 
-        let previous = Release::from(range);
+        let previous = Release::try_from(range)?;
         let previous = self.populate_previous_release(previous, target)?;
         release.previous = Some(Box::new(previous));
 
@@ -179,7 +200,7 @@ impl Repository {
         // get/validate the target oid
         let to = match to {
             None => self.get_head_commit_oid()?,
-            Some(to) => self.0.revparse_single(to)?.id(),
+            Some(to) => self.git.revparse_single(to)?.id(),
         };
 
         // Either user input, latest tag since `to`, or first commit
@@ -216,7 +237,7 @@ impl Repository {
             .map(OidOf::Tag)
             // Or an oid
             .unwrap_or_else(|_| {
-                let object = self.0.revparse_single(from).expect("Expected oid or tag");
+                let object = self.git.revparse_single(from).expect("Expected oid or tag");
 
                 // Is the oid pointing to a tag ?
                 let tag = self
@@ -233,7 +254,7 @@ impl Repository {
     }
 
     fn get_commit_range_from_spec(&self, spec: &str) -> Result<Vec<Commit>, Git2Error> {
-        let mut revwalk = self.0.revwalk()?;
+        let mut revwalk = self.git.revwalk()?;
 
         revwalk.push_range(spec)?;
 
@@ -241,7 +262,7 @@ impl Repository {
 
         for oid in revwalk {
             let oid = oid?;
-            let commit = self.0.find_commit(oid)?;
+            let commit = self.git.find_commit(oid)?;
             commits.push(commit);
         }
 
@@ -250,10 +271,10 @@ impl Repository {
 
     // Hide all commit after `starting_point` and get the closest tag
     fn get_latest_tag_starting_from(&self, starting_point: Oid) -> Result<Tag, Git2Error> {
-        let starting_point = self.0.find_commit(starting_point)?;
+        let starting_point = self.git.find_commit(starting_point)?;
         let starting_point = starting_point.parent(0)?;
         let first_commit = self.get_first_commit()?;
-        let mut revwalk = self.0.revwalk()?;
+        let mut revwalk = self.git.revwalk()?;
         let range = format!("{}..{}", first_commit, starting_point.id());
 
         revwalk.push_range(&range)?;
@@ -263,15 +284,21 @@ impl Repository {
         }
 
         let mut tags = vec![];
-        self.0
+        self.git
             .tag_foreach(|mut oid, name| {
                 let name = String::from_utf8_lossy(name);
                 let name = name.as_ref().strip_prefix("refs/tags/").unwrap();
 
                 // If this is an annotated tag, find the first parent commit
-                if self.0.revparse_single(name).unwrap().as_commit().is_none() {
+                if self
+                    .git
+                    .revparse_single(name)
+                    .unwrap()
+                    .as_commit()
+                    .is_none()
+                {
                     if let Some(commit) = self
-                        .0
+                        .git
                         .revparse_single([name, "^{}"].concat().as_str())
                         .unwrap()
                         .as_commit()
@@ -280,7 +307,7 @@ impl Repository {
                     }
                 };
 
-                if range.contains(&oid) {
+                if range.contains(&oid) && self.in_tag_scope(name) {
                     if let Ok(tag) = Tag::new(name, Some(oid)) {
                         tags.push(tag);
                     };
@@ -412,7 +439,7 @@ mod test {
         // Create an annotated tag
         let head = repo.get_head_commit().unwrap();
         let sig = git2::Signature::now("Author", "email@example.com")?;
-        repo.0
+        repo.git
             .tag("1.0.0", &head.into_object(), &sig, "the_msg", false)?;
 
         run_cmd!(
@@ -556,7 +583,7 @@ mod test {
     fn recursive_from_origin_to_head() -> Result<()> {
         // Arrange
         let repo = Repository::open(COCOGITTO_REPOSITORY)?;
-        let mut tag_count = repo.0.tag_names(None)?.len();
+        let mut tag_count = repo.git.tag_names(None)?.len();
         let head = repo.get_head_commit_oid()?;
         let latest = repo.get_latest_tag()?;
         let latest = latest.oid();
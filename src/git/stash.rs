@@ -3,9 +3,9 @@ use crate::git::repository::Repository;
 
 impl Repository {
     pub(crate) fn stash_failed_version(&mut self, version: &str) -> Result<(), Git2Error> {
-        let sig = self.0.signature()?;
+        let sig = self.git.signature()?;
         let message = &format!("cog_bump_{}", version);
-        self.0
+        self.git
             .stash_save(&sig, message, None)
             .map(|_| ())
             .map_err(Git2Error::StashError)
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use git2::{Oid, Tree};
+
+use crate::conventional::changelog::release::CommitStats;
+use crate::git::error::Git2Error;
+use crate::git::repository::Repository;
+
+/// Compute the diff stats of commits against their first parent.
+///
+/// Commit trees are cached by commit since, walking a release, each commit tree is
+/// also looked up as the parent tree of the next one.
+pub(crate) struct DiffStats<'repo> {
+    repository: &'repo Repository,
+    trees: HashMap<Oid, Tree<'repo>>,
+}
+
+impl<'repo> DiffStats<'repo> {
+    pub(crate) fn new(repository: &'repo Repository) -> Self {
+        DiffStats {
+            repository,
+            trees: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn stats(&mut self, oid: Oid) -> Result<CommitStats, Git2Error> {
+        let commit = self.repository.git.find_commit(oid)?;
+        let tree = self.tree(oid)?;
+        let parent_tree = match commit.parent_id(0) {
+            Ok(parent) => Some(self.tree(parent)?),
+            Err(_) => None,
+        };
+
+        let diff =
+            self.repository
+                .git
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+        let stats = diff.stats()?;
+
+        Ok(CommitStats {
+            files_changed: stats.files_changed(),
+            insertions: stats.insertions(),
+            deletions: stats.deletions(),
+        })
+    }
+
+    fn tree(&mut self, oid: Oid) -> Result<Tree<'repo>, Git2Error> {
+        if let Some(tree) = self.trees.get(&oid) {
+            return Ok(tree.clone());
+        }
+
+        let tree = self.repository.git.find_commit(oid)?.tree()?;
+        self.trees.insert(oid, tree.clone());
+        Ok(tree)
+    }
+}
@@ -16,7 +16,7 @@ impl Repository {
         options.include_unmodified(false);
 
         let statuses = self
-            .0
+            .git
             .statuses(Some(&mut options))
             .map_err(Git2Error::StatusError)?;
 
@@ -42,7 +42,21 @@ pub enum Changes {
     Modified(String),
 }
 
+impl Status {
+    pub(crate) fn path(&self) -> &str {
+        match self {
+            Status::Untracked(changes) | Status::UnCommitted(changes) => changes.path(),
+        }
+    }
+}
+
 impl Changes {
+    pub(crate) fn path(&self) -> &str {
+        match self {
+            New(p) | Renamed(p) | Deleted(p) | TypeChange(p) | Modified(p) => p,
+        }
+    }
+
     pub(crate) fn to_string(&self, color: &str) -> String {
         match &self {
             New(p) => format!("{}: {}", "new".color(color), p),
@@ -175,7 +189,7 @@ mod test {
         options.include_unmodified(false);
 
         let git_statuses = repo
-            .0
+            .git
             .statuses(Some(&mut options))
             .map_err(|err| anyhow!(err))?;
 
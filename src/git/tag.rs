@@ -4,43 +4,65 @@ use crate::SETTINGS;
 use git2::string_array::StringArray;
 use git2::Oid;
 use git2::Tag as Git2Tag;
+use lazy_static::lazy_static;
+use log::warn;
+use regex::Regex;
 use semver::Version;
+use serde::Serialize;
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Formatter;
 
+lazy_static! {
+    // Invalid patterns are rejected when loading the settings.
+    static ref TAG_PATTERN: Option<Regex> = SETTINGS
+        .bump
+        .tag_pattern
+        .as_deref()
+        .and_then(|pattern| Regex::new(pattern).ok());
+    static ref TAG_FORMAT: Option<Regex> = SETTINGS.bump.tag_format.as_deref().map(format_regex);
+}
+
+// A regex capturing the `scope` and the `version` of the tags named after `bump.tag_format`
+fn format_regex(format: &str) -> Regex {
+    let pattern = regex::escape(format)
+        .replace(r"\{scope\}", "(?P<scope>.+?)")
+        .replace(r"\{version\}", "(?P<version>.+)");
+
+    Regex::new(&format!("^{}$", pattern)).expect("escaped tag format should be a valid regex")
+}
+
 impl Repository {
     /// Given a tag name return a [`Tag`], this will fail if the requested
     /// tag (without configured prefix) is not semver compliant or if the tag
     /// does not exist.
     pub fn resolve_tag(&self, tag: &str) -> Result<Tag, TagError> {
-        let without_prefix = Tag::strip_prefix(tag)?;
-
         // Ensure the tag is SemVer compliant
-        Version::parse(without_prefix).map_err(|err| TagError::semver(without_prefix, err))?;
+        Tag::new(tag, None)?.to_version()?;
 
         self.resolve_lightweight_tag(tag)
     }
 
     /// Resolve a tag from a given `&str`, return an error if the tag is not found.
     fn resolve_lightweight_tag(&self, tag: &str) -> Result<Tag, TagError> {
-        self.0
+        self.git
             .resolve_reference_from_short_name(tag)
+            .and_then(|reference| reference.peel_to_commit())
             .map_err(|err| TagError::not_found(tag, err))
-            .map(|reference| reference.target().unwrap())
-            .map(|oid| Tag::new(tag, Some(oid)))?
+            .map(|commit| Tag::new(tag, Some(commit.id())))?
     }
 
-    pub(crate) fn create_tag(&self, name: &str) -> Result<(), Git2Error> {
+    pub(crate) fn create_tag(&self, name: &str, message: &str) -> Result<(), Git2Error> {
         if self.get_diff(true).is_some() {
             let statuses = self.get_statuses()?;
             return Err(Git2Error::ChangesNeedToBeCommitted(statuses));
         }
 
         let head = self.get_head_commit().unwrap();
-        self.0
-            .tag_lightweight(name, &head.into_object(), false)
+        let signature = self.git.signature()?;
+        self.git
+            .tag(name, &head.into_object(), &signature, message, false)
             .map(|_| ())
             .map_err(Git2Error::from)
     }
@@ -61,27 +83,76 @@ impl Repository {
             .collect())
     }
 
+    /// Return every SemVer compliant tag sorted by version, non compliant tags are skipped.
+    pub(crate) fn semver_tags(&self) -> Result<Vec<Tag>, TagError> {
+        let mut tags: Vec<Tag> = self
+            .all_tags()?
+            .into_iter()
+            .filter(|tag| match tag.to_version() {
+                Ok(_) => true,
+                Err(err) => {
+                    warn!("Skipping {}", err.to_string().trim_end());
+                    false
+                }
+            })
+            .collect();
+
+        tags.sort();
+        Ok(tags)
+    }
+
     pub(crate) fn get_latest_tag_oid(&self) -> Result<Oid, TagError> {
         self.get_latest_tag()
             .map(|tag| tag.oid_unchecked().to_owned())
     }
 
-    fn tags(&self) -> Result<StringArray, TagError> {
-        let pattern = SETTINGS
-            .tag_prefix
-            .as_ref()
-            .map(|prefix| format!("{}*", prefix));
+    /// Whether `name` is a version tag of the selected package when `bump.tag_format`
+    /// contains `{scope}`, always true otherwise
+    pub(crate) fn in_tag_scope(&self, name: &str) -> bool {
+        match (TAG_FORMAT.as_ref(), self.tag_scope()) {
+            (Some(format), Some(scope)) => format
+                .captures(name)
+                .and_then(|captures| captures.name("scope"))
+                .is_some_and(|tag_scope| tag_scope.as_str() == scope),
+            _ => true,
+        }
+    }
 
-        self.0
+    fn tags(&self) -> Result<StringArray, TagError> {
+        let pattern = match SETTINGS.bump.tag_format.as_deref() {
+            Some(format) => Some(
+                format
+                    .replace("{scope}", self.tag_scope().unwrap_or("*"))
+                    .replace("{version}", "*"),
+            ),
+            None => SETTINGS
+                .tag_prefix
+                .as_ref()
+                .map(|prefix| format!("{}*", prefix)),
+        };
+
+        self.git
             .tag_names(pattern.as_deref())
             .map_err(|err| TagError::NoMatchFound { pattern, err })
     }
 }
 
+/// A release tag as listed by `cog tags`
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct TagSummary {
+    pub tag: String,
+    pub id: String,
+    /// Number of commits since the previous tag, or since the first commit
+    pub commits: usize,
+    pub latest: bool,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Tag {
     tag: String,
     oid: Option<Oid>,
+    // The full tag name when the version was extracted with `bump.tag_pattern`
+    name: Option<String>,
 }
 
 impl TryFrom<Git2Tag<'_>> for Tag {
@@ -107,11 +178,38 @@ impl Tag {
     }
 
     pub(crate) fn new(name: &str, oid: Option<Oid>) -> Result<Tag, TagError> {
-        let tag = Tag::strip_prefix(name)?.to_string();
-        Ok(Tag { tag, oid })
+        match TAG_PATTERN.as_ref().or(TAG_FORMAT.as_ref()) {
+            Some(pattern) => {
+                let version = pattern
+                    .captures(name)
+                    .and_then(|captures| captures.name("version"))
+                    .ok_or_else(|| TagError::PatternMismatch {
+                        pattern: pattern.to_string(),
+                        tag: name.to_string(),
+                    })?;
+
+                Ok(Tag {
+                    tag: version.as_str().to_string(),
+                    oid,
+                    name: Some(name.to_string()),
+                })
+            }
+            None => {
+                let tag = Tag::strip_prefix(name)?.to_string();
+                Ok(Tag {
+                    tag,
+                    oid,
+                    name: None,
+                })
+            }
+        }
     }
 
     pub(crate) fn to_string_with_prefix(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+
         match SETTINGS.tag_prefix.as_ref() {
             None => self.tag.to_string(),
             Some(prefix) => format!("{}{}", prefix, self.tag),
@@ -156,6 +254,7 @@ impl PartialOrd<Tag> for Tag {
 #[cfg(test)]
 mod test {
     use crate::git::repository::Repository;
+    use crate::git::tag::format_regex;
     use anyhow::Result;
     use cmd_lib::run_cmd;
     use sealed_test::prelude::*;
@@ -230,6 +329,21 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn format_regex_captures_scope_and_version() {
+        // Arrange
+        let regex = format_regex("{scope}@{version}");
+
+        // Act
+        let captures = regex.captures("api@1.2.0");
+
+        // Assert
+        let captures = captures.expect("tag should match the format");
+        assert_that!(captures.name("scope").map(|m| m.as_str())).is_equal_to(Some("api"));
+        assert_that!(captures.name("version").map(|m| m.as_str())).is_equal_to(Some("1.2.0"));
+        assert_that!(regex.is_match("1.2.0")).is_false();
+    }
+
     #[sealed_test]
     fn get_latest_tag_oid_ok() -> Result<()> {
         // Arrange
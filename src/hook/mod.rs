@@ -9,6 +9,7 @@ use std::str::FromStr;
 
 use semver::Version;
 
+use crate::git::tag::Tag;
 use crate::SETTINGS;
 use parser::Token;
 
@@ -31,6 +32,10 @@ impl HookVersion {
         }
     }
     pub(crate) fn to_version(&self) -> Result<Version> {
+        if SETTINGS.bump.tag_format.is_some() {
+            return Ok(Tag::new(&self.prefixed_tag, None)?.to_version()?);
+        }
+
         match SETTINGS.tag_prefix.as_ref() {
             Some(prefix) => {
                 if self.prefixed_tag.starts_with(prefix) {
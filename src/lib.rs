@@ -1,5 +1,5 @@
 use ::log::{error, info, warn};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write as FmtWrite;
 use std::fs::File;
 use std::io::Write;
@@ -7,33 +7,45 @@ use std::path::Path;
 use std::process::{exit, Command, Stdio};
 
 use anyhow::{anyhow, bail, ensure, Context, Result};
+use chrono::{NaiveDateTime, Utc};
 use colored::*;
 use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
 use conventional_commit_parser::parse_footers;
-use git2::{Oid, RebaseOptions};
+use git2::{Commit as Git2Commit, Oid, RebaseOptions};
 use globset::Glob;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use semver::{Prerelease, Version};
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
 use tempfile::TempDir;
 
 use crate::log::filter::CommitFilters;
-use conventional::commit::{verify, Commit, CommitConfig};
+use crate::log::order::LogOrder;
+use conventional::commit::{is_skipped_commit, signoff_footer, verify, Commit, CommitConfig};
 use conventional::version::VersionIncrement;
 use error::{CogCheckReport, PreHookError};
 use git::repository::Repository;
 use hook::Hook;
-use settings::{HookType, Settings};
+use settings::{HookType, Preset, Settings};
 
-use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::parser::parse_sections;
+use crate::conventional::changelog::release::{ChangelogCommit, Release};
+use crate::conventional::changelog::renderer::Renderer;
 use crate::conventional::changelog::template::Template;
+use crate::conventional::check_cache::CheckCache;
+use crate::conventional::error::{BumpError, ConventionalCommitError};
 use crate::git::error::{Git2Error, TagError};
 use crate::git::oid::OidOf;
-use crate::git::revspec::RevspecPattern;
-use crate::git::tag::Tag;
+use crate::git::path::PathFilter;
+use crate::git::revspec::{CommitRange, RevspecPattern};
+use crate::git::stats::DiffStats;
+use crate::git::status::Statuses;
+use crate::git::tag::{Tag, TagSummary};
 use crate::hook::HookVersion;
 
 pub mod conventional;
+pub mod doctor;
 pub mod error;
 pub mod git;
 pub mod hook;
@@ -62,6 +74,11 @@ lazy_static! {
 }
 
 pub fn init<S: AsRef<Path> + ?Sized>(path: &S) -> Result<()> {
+    init_with_preset(path, None)
+}
+
+/// Same as [`init`], writing the given [`Preset`] instead of the default config
+pub fn init_with_preset<S: AsRef<Path> + ?Sized>(path: &S, preset: Option<Preset>) -> Result<()> {
     let path = path.as_ref();
 
     if !path.exists() {
@@ -88,18 +105,18 @@ pub fn init<S: AsRef<Path> + ?Sized>(path: &S) -> Result<()> {
         },
     };
 
-    let settings = Settings::default();
     let settings_path = path.join(CONFIG_PATH);
     if settings_path.exists() {
         eprint!("Found {} in {:?}, Nothing to do", CONFIG_PATH, &path);
         exit(1);
     } else {
-        std::fs::write(
-            &settings_path,
-            toml::to_string(&settings)
+        let settings = match preset {
+            Some(preset) => preset.content().to_string(),
+            None => toml::to_string(&Settings::default())
                 .map_err(|err| anyhow!("failed to serialize {}\n\ncause: {}", CONFIG_PATH, err))?,
-        )
-        .map_err(|err| {
+        };
+
+        std::fs::write(&settings_path, settings).map_err(|err| {
             anyhow!(
                 "failed to write file `{:?}`\n\ncause: {}",
                 settings_path,
@@ -118,6 +135,83 @@ pub fn init<S: AsRef<Path> + ?Sized>(path: &S) -> Result<()> {
     Ok(())
 }
 
+/// Options of [`CocoGitto::create_version`], the defaults create a regular version commit and tag
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BumpOptions<'a> {
+    /// Pre-release identifier of the new version, e.g. `alpha.1`
+    pub pre_release: Option<&'a str>,
+    /// Bump profile whose hooks to run, instead of the default bump hooks
+    pub hooks_config: Option<&'a str>,
+    /// Only compute the new version, without any side effect
+    pub dry_run: bool,
+    /// Only create the version tag, without generating the changelog
+    pub no_changelog: bool,
+    /// Write the changelog to this file instead of the configured one
+    pub changelog_path: Option<&'a Path>,
+    /// Amend the last commit with the version changes instead of creating a version commit
+    pub amend: bool,
+    /// Append the `bump.skip_ci` marker to the version commit
+    pub skip_ci: bool,
+    /// Bump even with uncommitted changes, they are included in the version commit
+    pub allow_dirty: bool,
+    /// Release a patch version even without any `bump.release_commit_types` commit
+    pub force: bool,
+    /// Compute the version from the commits after this ref instead of the latest tag
+    pub from_ref: Option<&'a str>,
+    /// Version commit message template instead of `bump.commit_message_template`
+    pub commit_message: Option<&'a str>,
+}
+
+/// Outcome of [`CocoGitto::create_version`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReleaseSummary {
+    /// The new version, without tag prefix
+    pub version: String,
+    /// The previous version tag, `None` on the first release
+    pub previous_version: Option<String>,
+    /// The new version tag, including the tag prefix if any
+    pub tag: String,
+    /// Number of commits released since the previous version
+    pub commits: usize,
+    /// The release changelog section, `None` on dry run or when the changelog is skipped
+    pub changelog: Option<String>,
+}
+
+/// The outcome of checking a single commit with [`CocoGitto::check_range`]
+#[derive(Debug)]
+pub struct CheckResult {
+    pub oid: String,
+    /// The reason the commit is not compliant, `None` if it passed
+    pub error: Option<ConventionalCommitError>,
+}
+
+impl CheckResult {
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A [`CocoGitto::create_version`] dry run outcome along with the commits
+/// and changelog section the bump would release
+#[derive(Debug, Serialize)]
+pub struct DryRunSummary<'a> {
+    pub version: String,
+    pub previous_version: Option<String>,
+    pub tag: String,
+    pub commits: Vec<ChangelogCommit<'a>>,
+    pub changelog: String,
+}
+
+/// A release of the repository history, see [`CocoGitto::write_release_history`]
+#[derive(Debug, Serialize)]
+pub struct ReleaseHistoryEntry<'a> {
+    pub version: &'a OidOf,
+    /// Date of the release commit
+    pub date: NaiveDateTime,
+    /// Commits by changelog section title
+    pub commits: BTreeMap<String, Vec<&'a ChangelogCommit<'a>>>,
+}
+
 #[derive(Debug)]
 pub struct CocoGitto {
     repository: Repository,
@@ -125,9 +219,31 @@ pub struct CocoGitto {
 
 impl CocoGitto {
     pub fn get() -> Result<Self> {
-        let repository = Repository::open(&std::env::current_dir()?)?;
-        let _settings = Settings::get(&repository)?;
-        let _changelog_path = settings::changelog_path();
+        Self::from_path(&std::env::current_dir()?, None)
+    }
+
+    /// Open the repository containing `repo` and validate its settings, read from `config`
+    /// if any or from the repository `cog.toml` otherwise.
+    ///
+    /// Commit parsing and changelog generation rely on [`SETTINGS`], loaded once from the
+    /// repository of the current directory: opening a repository with different settings
+    /// is rejected.
+    pub fn from_path(repo: &Path, config: Option<&Path>) -> Result<Self> {
+        let repository = Repository::open(repo)?;
+        let settings = match config {
+            Some(config) => {
+                ensure!(config.exists(), "config file {:?} not found", config);
+                Settings::from_file(config)?
+            }
+            None => Settings::get(&repository)?,
+        };
+
+        ensure!(
+            settings == *SETTINGS,
+            "the settings of {:?} differ from the ones of the current directory, \
+            which are used for every repository",
+            repo
+        );
 
         Ok(CocoGitto { repository })
     }
@@ -136,6 +252,32 @@ impl CocoGitto {
         self.repository.get_author()
     }
 
+    /// The latest SemVer version tag, including the tag prefix if any
+    pub fn get_latest_release(&self) -> Option<String> {
+        self.repository
+            .get_latest_tag()
+            .ok()
+            .map(|tag| tag.to_string_with_prefix())
+    }
+
+    /// The revspec of the release `tag` starting from `previous_tag` instead of the tag preceding
+    /// it, e.g. after backports. Both must be version tags, `previous_tag` an ancestor of `tag`.
+    pub fn release_range(&self, previous_tag: &str, tag: &str) -> Result<String> {
+        let previous = self.repository.resolve_tag(previous_tag)?;
+        let release = self.repository.resolve_tag(tag)?;
+
+        ensure!(
+            self.repository
+                .git
+                .graph_descendant_of(*release.oid_unchecked(), *previous.oid_unchecked())?,
+            "tag {} is not an ancestor of {}",
+            previous_tag,
+            tag
+        );
+
+        Ok(format!("{}..{}", previous_tag, tag))
+    }
+
     pub fn get_repo_tag_name(&self) -> Option<String> {
         let repo_path = self.repository.get_repo_dir()?.iter().last()?;
         let mut repo_tag_name = repo_path.to_str()?.to_string();
@@ -180,7 +322,7 @@ impl CocoGitto {
         if let Some(last_errored_commit) = last_errored_commit {
             let commit = self
                 .repository
-                .0
+                .git
                 .find_commit(last_errored_commit.to_owned())?;
 
             let rebase_start = if commit.parent_count() == 0 {
@@ -189,18 +331,18 @@ impl CocoGitto {
                 commit.parent_id(0)?
             };
 
-            let commit = self.repository.0.find_annotated_commit(rebase_start)?;
+            let commit = self.repository.git.find_annotated_commit(rebase_start)?;
             let mut options = RebaseOptions::new();
 
             let mut rebase =
                 self.repository
-                    .0
+                    .git
                     .rebase(None, Some(&commit), None, Some(&mut options))?;
 
             while let Some(op) = rebase.next() {
                 if let Ok(rebase_operation) = op {
                     let oid = rebase_operation.id();
-                    let original_commit = self.repository.0.find_commit(oid)?;
+                    let original_commit = self.repository.git.find_commit(oid)?;
                     if errored_commits.contains(&oid) {
                         warn!("Found errored commits:{}", &oid.to_string()[0..7]);
                         let file_path = dir.path().join(&commit.id().to_string());
@@ -262,7 +404,12 @@ impl CocoGitto {
         Ok(())
     }
 
-    pub fn check(&self, check_from_latest_tag: bool, ignore_merge_commits: bool) -> Result<()> {
+    pub fn check(
+        &self,
+        check_from_latest_tag: bool,
+        ignore_merge_commits: bool,
+        use_cache: bool,
+    ) -> Result<()> {
         let commit_range = if check_from_latest_tag {
             self.repository
                 .get_commit_range(&RevspecPattern::default())?
@@ -270,23 +417,91 @@ impl CocoGitto {
             self.repository.all_commits()?
         };
 
-        let errors: Vec<_> = if ignore_merge_commits {
-            commit_range
-                .commits
-                .iter()
-                .filter(|commit| !commit.message().unwrap_or("").starts_with("Merge "))
-                .map(Commit::from_git_commit)
-                .filter_map(Result::err)
-                .collect()
+        self.report_check(commit_range, ignore_merge_commits, use_cache)
+    }
+
+    /// Check the commits of the current branch since it forked from the default branch,
+    /// see [`CocoGitto::pr_base`].
+    pub fn check_pull_request(&self, ignore_merge_commits: bool, use_cache: bool) -> Result<()> {
+        let pattern = format!("{}..", self.pr_base()?);
+        let commit_range = self
+            .repository
+            .get_commit_range(&RevspecPattern::from(pattern.as_str()))?;
+
+        self.report_check(commit_range, ignore_merge_commits, use_cache)
+    }
+
+    /// The `default_branch` setting, or the branch `origin/HEAD` points to.
+    pub fn default_branch(&self) -> Result<String> {
+        SETTINGS
+            .default_branch
+            .clone()
+            .or_else(|| self.repository.detect_default_branch())
+            .ok_or_else(|| {
+                anyhow!(
+                    "unable to detect the default branch without an `origin/HEAD` reference, \
+                    set `default_branch` in cog.toml"
+                )
+            })
+    }
+
+    /// The commit HEAD forked from the default branch at, the remote `origin/<branch>`
+    /// being preferred to the local branch.
+    pub fn pr_base(&self) -> Result<Oid> {
+        let branch = self.default_branch()?;
+        let base = self
+            .repository
+            .git
+            .revparse_single(&format!("origin/{}", branch))
+            .or_else(|_| self.repository.git.revparse_single(&branch))
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|err| anyhow!("default branch `{}` not found\n\ncause: {}", branch, err))?;
+        let head = self.repository.get_head_commit_oid()?;
+
+        self.repository
+            .git
+            .merge_base(head, base.id())
+            .map_err(|err| anyhow!("HEAD has no common ancestor with `{}`: {}", branch, err))
+    }
+
+    // Check `commit_range`, returning a `CogCheckReport` error listing the non compliant commits
+    fn report_check(
+        &self,
+        commit_range: CommitRange,
+        ignore_merge_commits: bool,
+        use_cache: bool,
+    ) -> Result<()> {
+        let mut cache = if use_cache {
+            Some(CheckCache::load(self.repository.git.path(), &SETTINGS))
         } else {
-            commit_range
-                .commits
-                .iter()
-                .map(Commit::from_git_commit)
-                .filter_map(Result::err)
-                .collect()
+            None
         };
 
+        let results = Self::check_commits(&commit_range, ignore_merge_commits, cache.as_ref());
+
+        if let Some(cache) = &mut cache {
+            let cached = results
+                .iter()
+                .filter(|result| result.passed() && cache.contains(&result.oid))
+                .count();
+            if cached > 0 {
+                info!("Skipped {} cached commit(s)", cached);
+            }
+
+            for result in results.iter().filter(|result| result.passed()) {
+                cache.insert(result.oid.clone());
+            }
+
+            if let Err(err) = cache.save() {
+                warn!("Failed to write the check cache: {}", err);
+            }
+        }
+
+        let errors: Vec<_> = results
+            .into_iter()
+            .filter_map(|result| result.error)
+            .collect();
+
         if errors.is_empty() {
             let msg = "No errored commits".green();
             info!("{}", msg);
@@ -294,19 +509,467 @@ impl CocoGitto {
         } else {
             let report = CogCheckReport {
                 from: commit_range.from,
-                errors: errors.into_iter().map(|err| *err).collect(),
+                errors,
             };
-            Err(anyhow!("{}", report))
+            Err(report.into())
+        }
+    }
+
+    /// Rewrite the commits of the checked range whose message only has trivial formatting
+    /// issues, see [`conventional::commit::fix_message`], rebasing their descendants.
+    /// Commits still invalid once fixed, pushed to a remote branch or part of a tag are left
+    /// untouched. With `dry_run`, the proposed messages are printed and history is left as is.
+    /// Returns the number of rewritten, or to be rewritten, commits.
+    pub fn fix_commits(
+        &self,
+        check_from_latest_tag: bool,
+        ignore_merge_commits: bool,
+        dry_run: bool,
+    ) -> Result<usize> {
+        let commit_range = if check_from_latest_tag {
+            self.repository
+                .get_commit_range(&RevspecPattern::default())?
+        } else {
+            self.repository.all_commits()?
+        };
+
+        let tags = self.repository.all_tags()?;
+        let mut messages = HashMap::new();
+        for commit in &commit_range.commits {
+            let message = match commit.message() {
+                Some(message) => message,
+                None => continue,
+            };
+
+            if ignore_merge_commits && message.starts_with("Merge ") {
+                continue;
+            }
+
+            let fixed = conventional::commit::fix_message(message);
+            if fixed == message || Commit::parse(&fixed, None, None).is_err() {
+                continue;
+            }
+
+            let oid = commit.id();
+            let mut tagged = false;
+            for tag in &tags {
+                let tag_oid = *tag.oid_unchecked();
+                tagged |=
+                    tag_oid == oid || self.repository.git.graph_descendant_of(tag_oid, oid)?;
+            }
+
+            if tagged || self.repository.ensure_not_pushed(oid).is_err() {
+                warn!(
+                    "Not fixing commit {}, it was already released or pushed",
+                    &oid.to_string()[0..7]
+                );
+                continue;
+            }
+
+            if dry_run {
+                println!("{}\n{}\n", &oid.to_string()[0..7], fixed.trim_end());
+            }
+
+            messages.insert(oid, fixed);
+        }
+
+        if dry_run {
+            info!("Would fix {} commit(s)", messages.len());
+        } else if !messages.is_empty() {
+            self.repository.reword_commits(&messages)?;
+            info!("{}", format!("Fixed {} commit(s)", messages.len()).green());
+        }
+
+        Ok(messages.len())
+    }
+
+    /// Check each commit of the `from..to` range against the conventional commit specification.
+    /// `from` defaults to the latest tag, or the first commit, and `to` to HEAD.
+    pub fn check_range(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+        ignore_merge_commits: bool,
+    ) -> Result<Vec<CheckResult>> {
+        let pattern = format!("{}..{}", from.unwrap_or_default(), to.unwrap_or_default());
+        let commit_range = self
+            .repository
+            .get_commit_range(&RevspecPattern::from(pattern.as_str()))?;
+
+        Ok(Self::check_commits(
+            &commit_range,
+            ignore_merge_commits,
+            None,
+        ))
+    }
+
+    // Commits in `cache` are known to be compliant and are not parsed again.
+    fn check_commits(
+        commit_range: &CommitRange,
+        ignore_merge_commits: bool,
+        cache: Option<&CheckCache>,
+    ) -> Vec<CheckResult> {
+        let commits: Vec<&Git2Commit> = commit_range
+            .commits
+            .iter()
+            .filter(|commit| {
+                !ignore_merge_commits || !commit.message().unwrap_or("").starts_with("Merge ")
+            })
+            .filter(|commit| !is_skipped_commit(commit))
+            .collect();
+
+        let mut results: Vec<CheckResult> = commits
+            .iter()
+            .map(|commit| {
+                let oid = commit.id().to_string();
+                if cache.is_some_and(|cache| cache.contains(&oid)) {
+                    return CheckResult { oid, error: None };
+                }
+
+                CheckResult {
+                    oid,
+                    error: match Commit::from_git_commit(commit) {
+                        Ok(commit) => {
+                            commit.warn_body_line_length();
+                            commit.lint().err().map(|err| *err)
+                        }
+                        Err(err) => Some(*err),
+                    },
+                }
+            })
+            .collect();
+
+        if SETTINGS.commit.flag_duplicates {
+            Self::flag_duplicate_subjects(&commits, &mut results);
+        }
+
+        results
+    }
+
+    // Flag the newest commit of each group of commits sharing the same subject, listing
+    // the whole group. Commits already errored are left as is.
+    fn flag_duplicate_subjects(commits: &[&Git2Commit], results: &mut [CheckResult]) {
+        let mut by_subject: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (idx, commit) in commits.iter().enumerate() {
+            let subject = commit.summary().unwrap_or_default().trim();
+            if !subject.is_empty() {
+                by_subject.entry(subject).or_default().push(idx);
+            }
+        }
+
+        for (subject, indexes) in by_subject {
+            // Commits are walked newest first
+            let newest = indexes[0];
+            if indexes.len() < 2 || results[newest].error.is_some() {
+                continue;
+            }
+
+            results[newest].error = Some(ConventionalCommitError::DuplicateSubject {
+                oid: commits[newest].id().to_string(),
+                summary: subject.to_string(),
+                duplicates: indexes
+                    .iter()
+                    .map(|&idx| commits[idx].id().to_string())
+                    .collect(),
+                author: commits[newest]
+                    .author()
+                    .name()
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+    }
+
+    // The commit `from_ref` points to, which must be HEAD or one of its ancestors
+    fn resolve_from_ref(&self, from_ref: &str, head: Oid) -> Result<Oid> {
+        let oid = self
+            .repository
+            .git
+            .revparse_single(from_ref)
+            .and_then(|object| object.peel_to_commit())
+            .map(|commit| commit.id())
+            .map_err(|err| anyhow!("reference `{}` not found\n\ncause: {}", from_ref, err))?;
+
+        ensure!(
+            oid == head || self.repository.git.graph_descendant_of(head, oid)?,
+            "reference `{}` is not reachable from HEAD",
+            from_ref
+        );
+
+        Ok(oid)
+    }
+
+    /// Rewrite the message of `revision`, HEAD or one of its ancestors, into a conventional
+    /// commit message. The message is read from `$EDITOR` when `message` is `None`.
+    /// Commits already pushed to a remote branch are only rewritten with `force`.
+    pub fn reword(&self, revision: &str, message: Option<&str>, force: bool) -> Result<Oid> {
+        let commit = self
+            .repository
+            .git
+            .revparse_single(revision)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|err| anyhow!("commit `{}` not found\n\ncause: {}", revision, err))?;
+        let oid = commit.id();
+
+        let head = self.repository.get_head_commit_oid()?;
+        ensure!(
+            oid == head || self.repository.git.graph_descendant_of(head, oid)?,
+            "commit {} is not reachable from HEAD",
+            oid
+        );
+
+        if !force {
+            self.repository.ensure_not_pushed(oid)?;
+        }
+
+        let new_message = match message {
+            Some(message) => message.to_string(),
+            None => {
+                let editor = std::env::var("EDITOR")
+                    .map_err(|_err| anyhow!("the 'EDITOR' environment variable was not found"))?;
+                let dir = TempDir::new()?;
+                let file_path = dir.path().join(oid.to_string());
+
+                let hint = format!(
+                    "# Rewording commit {}\
+                \n# Replace this message with a conventional commit compliant one\n",
+                    oid
+                );
+                let mut message_bytes: Vec<u8> = hint.into();
+                message_bytes.extend_from_slice(commit.message_bytes());
+                std::fs::write(&file_path, &message_bytes)?;
+
+                Command::new(&editor)
+                    .arg(&file_path)
+                    .stdout(Stdio::inherit())
+                    .stdin(Stdio::inherit())
+                    .stderr(Stdio::inherit())
+                    .output()?;
+
+                std::fs::read_to_string(&file_path)?
+                    .lines()
+                    .filter(|line| !line.starts_with('#'))
+                    .join("\n")
+            }
+        };
+        let new_message = new_message.trim();
+
+        verify(self.repository.get_author().ok(), new_message, false)
+            .map_err(|err| anyhow!("{}\n\t{}", "Message is not compliant".red(), err))?;
+
+        let new_head = self.repository.reword_commit(oid, new_message)?;
+        info!(
+            "Reworded commit {} to \"{}\"",
+            &oid.to_string()[0..7],
+            new_message.lines().next().unwrap_or("")
+        );
+
+        Ok(new_head)
+    }
+
+    /// Replace the scope of HEAD, e.g. after a typo, keeping the rest of its message.
+    pub fn amend_scope(&self, scope: &str) -> Result<Oid> {
+        self.amend_head_header(|message| message.scope = Some(scope.to_string()))
+    }
+
+    /// Replace the commit type of HEAD, keeping the rest of its message.
+    pub fn amend_type(&self, commit_type: &str) -> Result<Oid> {
+        self.amend_head_header(|message| message.commit_type = CommitType::from(commit_type))
+    }
+
+    fn amend_head_header(&self, amend: impl FnOnce(&mut ConventionalCommit)) -> Result<Oid> {
+        let head = self.repository.get_head_commit()?;
+        self.repository.ensure_not_pushed(head.id())?;
+
+        let message = head.message().unwrap_or_default().trim();
+        let mut conventional_message = conventional_commit_parser::parse(message)
+            .map_err(|err| anyhow!("{}\n\t{}", "HEAD is not a conventional commit".red(), err))?;
+        amend(&mut conventional_message);
+        let new_message = conventional_message.to_string();
+
+        verify(self.repository.get_author().ok(), &new_message, false)
+            .map_err(|err| anyhow!("{}\n\t{}", "Message is not compliant".red(), err))?;
+
+        let new_head = self.repository.reword_commit(head.id(), &new_message)?;
+        info!(
+            "Amended HEAD to \"{}\"",
+            new_message.lines().next().unwrap_or("")
+        );
+
+        Ok(new_head)
+    }
+
+    /// Verify a commit message file before the commit is created, defaulting to
+    /// `.git/COMMIT_EDITMSG`. With `fix`, trivial formatting issues are corrected
+    /// and written back to the file first.
+    pub fn diff_check(&self, path: Option<&Path>, fix: bool) -> Result<()> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => self.repository.git.path().join("COMMIT_EDITMSG"),
+        };
+
+        let mut message = std::fs::read_to_string(&path)
+            .map_err(|err| anyhow!("failed to read `{:?}`\n\ncause: {}", path, err))?;
+
+        if fix {
+            let fixed = conventional::commit::fix_message(&message);
+            if fixed != message {
+                std::fs::write(&path, &fixed)?;
+                info!("Fixed commit message in {:?}", path);
+                message = fixed;
+            }
+        }
+
+        // Commits being created must use the canonical header, only history is parsed leniently
+        ensure!(
+            conventional::commit::with_separator_space(&message) == message,
+            "Missing space after `:` in the commit header, use `--fix` to correct it"
+        );
+
+        let author = self.get_committer().ok();
+        verify(author, &message, SETTINGS.ignore_merge_commits)?;
+        Ok(())
+    }
+
+    pub fn get_log(
+        &self,
+        filters: CommitFilters,
+        order: LogOrder,
+        full_hash: bool,
+        show_scope: bool,
+    ) -> Result<String> {
+        let logs = self
+            .get_filtered_commits(&filters, order)?
+            .into_iter()
+            // Format
+            .map(|commit| match commit {
+                Ok(commit) => commit.get_log(full_hash, show_scope),
+                Err(err) => err.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Ok(logs)
+    }
+
+    /// Render the commits `cog log` would display with the given filters as a markdown list,
+    /// errored commits are rendered as fenced code blocks.
+    pub fn get_markdown_log(
+        &self,
+        filters: CommitFilters,
+        order: LogOrder,
+        full_hash: bool,
+        show_scope: bool,
+    ) -> Result<String> {
+        let logs = self
+            .get_filtered_commits(&filters, order)?
+            .into_iter()
+            .map(|commit| match commit {
+                Ok(commit) => commit.get_markdown_log(full_hash, show_scope),
+                Err(err) => format!("```text\n{}\n```", err.to_string().trim_end()),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Ok(logs)
+    }
+
+    /// Write the commits `cog log` would display with the given filters as a JSON array.
+    /// The commits are collected in memory before being written.
+    pub fn write_log_json<W: Write>(
+        &self,
+        filters: CommitFilters,
+        order: LogOrder,
+        writer: W,
+    ) -> Result<()> {
+        let mut serializer = serde_json::Serializer::pretty(writer);
+        let mut commits = serializer.serialize_seq(None)?;
+        for commit in self
+            .get_filtered_commits(&filters, order)?
+            .into_iter()
+            .flatten()
+        {
+            commits.serialize_element(&ChangelogCommit::from(commit))?;
         }
+
+        commits.end()?;
+        Ok(())
+    }
+
+    /// Write every release of the repository, newest first, as a JSON array of
+    /// [`ReleaseHistoryEntry`]. The whole release history, with its commits, is loaded
+    /// in memory before being written.
+    pub fn write_release_history<W: Write>(&self, writer: W) -> Result<()> {
+        let release = self
+            .repository
+            .get_release_range(RevspecPattern::from(".."))?;
+
+        let mut serializer = serde_json::Serializer::pretty(writer);
+        let mut releases = serializer.serialize_seq(None)?;
+        let mut current = Some(&release);
+        while let Some(release) = current {
+            let mut commits: BTreeMap<String, Vec<&ChangelogCommit>> = BTreeMap::new();
+            for commit in &release.commits {
+                commits
+                    .entry(commit.changelog_title())
+                    .or_default()
+                    .push(commit);
+            }
+
+            let release_commit = self.repository.git.find_commit(*release.version.oid())?;
+            releases.serialize_element(&ReleaseHistoryEntry {
+                version: &release.version,
+                date: NaiveDateTime::from_timestamp(release_commit.time().seconds(), 0),
+                commits,
+            })?;
+
+            current = release.previous.as_deref();
+        }
+
+        releases.end()?;
+        Ok(())
+    }
+
+    /// Number of commits `cog log` would display with the given filters.
+    pub fn count_log(&self, filters: CommitFilters) -> Result<usize> {
+        Ok(self
+            .get_filtered_commits(&filters, LogOrder::default())?
+            .len())
     }
 
-    pub fn get_log(&self, filters: CommitFilters) -> Result<String> {
-        let commits = self.repository.all_commits()?;
-        let logs = commits
+    fn get_filtered_commits(
+        &self,
+        filters: &CommitFilters,
+        order: LogOrder,
+    ) -> Result<Vec<Result<Commit, Box<ConventionalCommitError>>>> {
+        let mut commits = self.repository.all_commits_sorted(order.sort())?;
+        if order.reverse {
+            commits.commits.reverse();
+        }
+
+        if let Some(path) = filters.path() {
+            let mut path_filter = PathFilter::new(path);
+            let mut touching = vec![];
+            for commit in commits.commits {
+                if path_filter.touches(&commit)? {
+                    touching.push(commit);
+                }
+            }
+            commits.commits = touching;
+        }
+
+        let commits = commits
             .commits
             .iter()
             // Remove merge commits
             .filter(|commit| !commit.message().unwrap_or("").starts_with("Merge"))
+            // Remove version commits
+            .filter(|commit| {
+                !SETTINGS
+                    .bump
+                    .is_version_commit(commit.message().unwrap_or(""))
+            })
+            .filter(|commit| !is_skipped_commit(commit))
             .filter(|commit| filters.filter_git2_commit(commit))
             .map(Commit::from_git_commit)
             // Apply filters
@@ -314,15 +977,32 @@ impl CocoGitto {
                 Ok(commit) => filters.filters(commit),
                 Err(_) => filters.no_error(),
             })
-            // Format
-            .map(|commit| match commit {
-                Ok(commit) => commit.get_log(),
-                Err(err) => err.to_string(),
-            })
-            .collect::<Vec<String>>()
-            .join("\n");
+            .collect();
 
-        Ok(logs)
+        Ok(commits)
+    }
+
+    /// Get the repository SemVer tags sorted by version, along with the number of commits
+    /// between each tag and the previous one.
+    pub fn get_tags(&self) -> Result<Vec<TagSummary>> {
+        let tags = self.repository.semver_tags()?;
+        let latest = tags.last().cloned();
+        let mut summaries = Vec::with_capacity(tags.len());
+        let mut previous: Option<&Oid> = None;
+
+        for tag in &tags {
+            let oid = tag.oid_unchecked();
+            let commits = self.repository.count_commits(previous, oid)?;
+            summaries.push(TagSummary {
+                tag: tag.to_string_with_prefix(),
+                id: oid.to_string(),
+                commits,
+                latest: latest.as_ref() == Some(tag),
+            });
+            previous = Some(oid);
+        }
+
+        Ok(summaries)
     }
 
     /// Tries to get a commit message conforming to the Conventional Commit spec.
@@ -370,16 +1050,34 @@ impl CocoGitto {
         footer: Option<String>,
         is_breaking_change: bool,
         sign: bool,
+        signoff: bool,
     ) -> Result<()> {
         // Ensure commit type is known
         let commit_type = CommitType::from(commit_type);
 
         // Ensure footers are correctly formatted
-        let footers = match footer {
+        let mut footers = match footer {
             Some(footers) => parse_footers(&footers)?,
             None => Vec::with_capacity(0),
         };
 
+        if signoff {
+            let signature = self.repository.git.signature().map_err(|err| {
+                anyhow!(
+                    "cannot sign off without a git identity, set `user.name` and `user.email`\n\ncause: {}",
+                    err
+                )
+            })?;
+            match (signature.name(), signature.email()) {
+                (Some(name), Some(email)) if !name.is_empty() && !email.is_empty() => {
+                    footers.push(signoff_footer(name, email))
+                }
+                _ => bail!(
+                    "cannot sign off without a git identity, set `user.name` and `user.email`"
+                ),
+            }
+        }
+
         let conventional_message = ConventionalCommit {
             commit_type,
             scope,
@@ -398,20 +1096,92 @@ impl CocoGitto {
         let oid = self.repository.commit(&conventional_message, sign)?;
 
         // Pretty print a conventional commit summary
-        let commit = self.repository.0.find_commit(oid)?;
+        let commit = self.repository.git.find_commit(oid)?;
         let commit = Commit::from_git_commit(&commit)?;
         info!("{}", commit);
 
         Ok(())
     }
 
+    /// The version `cog bump` would create for `increment` from the latest tag, without any
+    /// side effect. Pre-release identifiers are not applied.
+    pub fn next_version(&self, increment: VersionIncrement) -> Result<Version> {
+        let current_tag = self.repository.get_latest_tag();
+        let current_version = Self::current_version(&current_tag)?;
+        self.increment_version(increment, &current_tag, &current_version, None, false)
+    }
+
+    // The version of the latest tag, `0.0.0` when there is none
+    fn current_version(current_tag: &Result<Tag, TagError>) -> Result<Version> {
+        match current_tag {
+            Ok(tag) => Ok(tag.to_version()?),
+            Err(err) if err == &TagError::NoTag => {
+                warn!("Failed to get current version, falling back to 0.0.0");
+                Ok(Version::new(0, 0, 0))
+            }
+            Err(err) => bail!("{}", err),
+        }
+    }
+
+    // Apply `increment` to the current version, from `from_oid` instead of the latest tag
+    // when set. With `force`, a range without releasable changes is released as a patch.
+    fn increment_version(
+        &self,
+        increment: VersionIncrement,
+        current_tag: &Result<Tag, TagError>,
+        current_version: &Version,
+        from_oid: Option<Oid>,
+        force: bool,
+    ) -> Result<Version> {
+        let next_version = match SETTINGS.bump.initial_version.as_deref() {
+            // First release: use the configured initial version unless one was explicitly given
+            Some(initial_version)
+                if current_tag.is_err() && !matches!(increment, VersionIncrement::Manual(_)) =>
+            {
+                Version::parse(initial_version)?
+            }
+            _ => match increment.bump_from(current_version, from_oid, &self.repository) {
+                // Release anyway, as a patch
+                Err(BumpError::NoReleasableChanges) if force => {
+                    VersionIncrement::Patch.bump(current_version, &self.repository)?
+                }
+                version => version?,
+            },
+        };
+
+        if next_version.le(current_version) || next_version.eq(current_version) {
+            let comparison = format!("{} <= {}", current_version, next_version).red();
+            let cause_key = "cause:".red();
+            let cause = format!(
+                "{} version MUST be greater than current one: {}",
+                cause_key, comparison
+            );
+
+            bail!("{}:\n\t{}\n", "SemVer Error".red().to_string(), cause);
+        };
+
+        Ok(next_version)
+    }
+
     pub fn create_version(
         &mut self,
         increment: VersionIncrement,
-        pre_release: Option<&str>,
-        hooks_config: Option<&str>,
-        dry_run: bool,
-    ) -> Result<()> {
+        options: BumpOptions,
+    ) -> Result<ReleaseSummary> {
+        let BumpOptions {
+            pre_release,
+            hooks_config,
+            dry_run,
+            no_changelog,
+            changelog_path,
+            amend,
+            skip_ci,
+            allow_dirty,
+            force,
+            from_ref,
+            commit_message,
+        } = options;
+
         if *SETTINGS == Settings::default() {
             let part1 = "Warning: using".yellow();
             let part2 = "with the default configuration. \n".yellow();
@@ -422,10 +1192,24 @@ impl CocoGitto {
                 part1, part2, part3, part4
             );
         }
-        let statuses = self.repository.get_statuses()?;
+        let changelog_path = changelog_path.unwrap_or_else(|| settings::changelog_path());
+        let statuses = self.repository.get_statuses()?.0.into_iter();
+        // The changelog file is about to be written anyway
+        let statuses = Statuses(
+            statuses
+                .filter(|status| no_changelog || Path::new(status.path()) != changelog_path)
+                .collect(),
+        );
 
         // Fail if repo contains un-staged or un-committed changes
-        ensure!(statuses.0.is_empty(), "{}", self.repository.get_statuses()?);
+        ensure!(
+            allow_dirty || statuses.0.is_empty(),
+            "{}\n\n{}",
+            "Cannot bump with a dirty working tree, commit or stash your changes first, \
+            or use `--allow-dirty` to include them in the version commit"
+                .red(),
+            statuses
+        );
 
         if !SETTINGS.branch_whitelist.is_empty() {
             if let Some(branch) = self.repository.get_branch_shorthand() {
@@ -446,58 +1230,91 @@ impl CocoGitto {
             }
         };
 
-        let current_tag = self.repository.get_latest_tag();
-        let current_version = match current_tag {
-            Ok(ref tag) => tag.to_version()?,
-            Err(ref err) if err == &TagError::NoTag => {
-                warn!("Failed to get current version, falling back to 0.0.0");
-                Version::new(0, 0, 0)
-            }
-            Err(ref err) => bail!("{}", err),
+        let head = self.repository.get_head_commit_oid()?;
+        let from_oid = match from_ref {
+            Some(from_ref) => Some(self.resolve_from_ref(from_ref, head)?),
+            None => None,
         };
 
-        let mut next_version = increment.bump(&current_version, &self.repository)?;
-
-        if next_version.le(&current_version) || next_version.eq(&current_version) {
-            let comparison = format!("{} <= {}", current_version, next_version).red();
-            let cause_key = "cause:".red();
-            let cause = format!(
-                "{} version MUST be greater than current one: {}",
-                cause_key, comparison
-            );
+        ensure!(
+            !SETTINGS.bump.has_scoped_tags() || self.repository.tag_scope().is_some(),
+            "`bump.tag_format` contains `{{scope}}`, select the package with `--package`"
+        );
 
-            bail!("{}:\n\t{}\n", "SemVer Error".red().to_string(), cause);
-        };
+        let current_tag = self.repository.get_latest_tag();
+        let current_version = Self::current_version(&current_tag)?;
+        let mut next_version =
+            self.increment_version(increment, &current_tag, &current_version, from_oid, force)?;
 
         if let Some(pre_release) = pre_release {
             next_version.pre = Prerelease::new(pre_release)?;
         }
 
-        let version_str = match &SETTINGS.tag_prefix {
-            None => next_version.to_string(),
-            Some(prefix) => format!("{}{}", prefix, next_version),
+        let version_str = SETTINGS
+            .bump
+            .tag_name(&next_version, self.repository.tag_scope());
+
+        let commit_message = match commit_message {
+            Some(template) => template.replace("{{version}}", &version_str),
+            None => SETTINGS.bump.commit_message(&version_str),
+        };
+        let commit_message = if skip_ci {
+            SETTINGS.bump.with_skip_ci(&commit_message)
+        } else {
+            commit_message
+        };
+
+        if SETTINGS.bump.verify_bump_commit {
+            if let Err(err) =
+                Commit::parse(&commit_message, None, None).and_then(|commit| commit.lint())
+            {
+                bail!("invalid version commit message: {}", err);
+            }
+        }
+
+        let previous_tag = current_tag.as_ref().ok();
+        let mut summary = ReleaseSummary {
+            version: next_version.to_string(),
+            previous_version: previous_tag.map(Tag::to_string_with_prefix),
+            tag: version_str.clone(),
+            commits: self.repository.count_commits(
+                from_oid
+                    .as_ref()
+                    .or_else(|| previous_tag.and_then(Tag::oid)),
+                &head,
+            )?,
+            changelog: None,
         };
 
         if dry_run {
-            print!("{}", version_str);
-            return Ok(());
+            return Ok(summary);
+        }
+
+        if amend {
+            self.repository.ensure_head_not_pushed()?;
         }
 
-        let origin = if current_version == Version::new(0, 0, 0) {
+        let origin = if let Some(from_oid) = from_oid {
+            from_oid.to_string()
+        } else if current_version == Version::new(0, 0, 0) {
             self.repository.get_first_commit()?.to_string()
         } else {
             current_tag?.oid_unchecked().to_string()
         };
 
-        let target = self.repository.get_head_commit_oid()?.to_string();
-        let pattern = (origin.as_str(), target.as_str());
-
-        let pattern = RevspecPattern::from(pattern);
+        let target = head.to_string();
+        let pattern = RevspecPattern::from((origin.as_str(), target.as_str()));
         let changelog = self.get_changelog_with_target_version(pattern, &version_str)?;
-
-        let path = settings::changelog_path();
         let template = SETTINGS.get_changelog_template()?;
-        changelog.write_to_file(path, template)?;
+
+        // The release section is the default tag message, even when it is not written
+        let changelog_section = if no_changelog {
+            changelog.into_markdown(template)?
+        } else {
+            let changelog_section = changelog.write_to_file(changelog_path, template)?;
+            summary.changelog = Some(changelog_section.clone());
+            changelog_section
+        };
 
         let current = self
             .repository
@@ -505,7 +1322,7 @@ impl CocoGitto {
             .map(|tag| HookVersion::new(&tag.to_string_with_prefix()))
             .ok();
 
-        let next_version = HookVersion::new(&Self::prefix_version(next_version.to_string()));
+        let next_version = HookVersion::new(&version_str);
 
         let hook_result = self.run_hooks(
             HookType::PreBump,
@@ -534,12 +1351,14 @@ impl CocoGitto {
 
         let version_str = Self::prefix_version(version_str);
 
-        self.repository.commit(
-            &format!("chore(version): {}", next_version.prefixed_tag),
-            false,
-        )?;
+        if amend {
+            self.repository.amend_head()?;
+        } else if !no_changelog || !self.repository.get_statuses()?.0.is_empty() {
+            // Without a changelog, the version commit only exists if hooks modified the tree
+            self.repository.commit(&commit_message, false)?;
+        }
 
-        self.repository.create_tag(&version_str)?;
+        self.create_version_tag(&version_str, &changelog_section)?;
 
         self.run_hooks(
             HookType::PostBump,
@@ -554,9 +1373,89 @@ impl CocoGitto {
         let bump = format!("{} -> {}", current, next_version.prefixed_tag).green();
         info!("Bumped version: {}", bump);
 
+        Ok(summary)
+    }
+
+    /// Only consider the version tags of `package`, which is required when `bump.tag_format`
+    /// contains `{scope}` and rejected otherwise.
+    pub fn select_package(&mut self, package: Option<&str>) -> Result<()> {
+        match package {
+            Some(package) => {
+                ensure!(
+                    SETTINGS.bump.has_scoped_tags(),
+                    "`--package` requires `{{scope}}` in `bump.tag_format`"
+                );
+                self.repository.set_tag_scope(package);
+            }
+            None => ensure!(
+                !SETTINGS.bump.has_scoped_tags(),
+                "`bump.tag_format` contains `{{scope}}`, select the package with `--package`"
+            ),
+        }
+
         Ok(())
     }
 
+    /// Finish a bump interrupted after its version commit was created but before it was tagged:
+    /// tag HEAD with the version of its version commit and run the post-bump hooks.
+    pub fn retry_bump(&mut self, hooks_config: Option<&str>) -> Result<ReleaseSummary> {
+        let head = self.repository.get_head_commit()?;
+        let message = head.message().unwrap_or("");
+        let version_str = SETTINGS
+            .bump
+            .commit_version(message)
+            .ok_or_else(|| anyhow!("HEAD is not a version commit, there is no bump to recover"))?
+            .to_string();
+
+        ensure!(
+            self.repository.git.revparse_single(&version_str).is_err(),
+            "version {} is already tagged, there is no bump to recover",
+            version_str
+        );
+
+        let previous_tag = self.repository.get_latest_tag().ok();
+        let changelog_section = std::fs::read_to_string(settings::changelog_path())
+            .ok()
+            .and_then(|changelog| {
+                parse_sections(&changelog)
+                    .into_iter()
+                    .find(|section| section.version == version_str)
+                    .map(|section| format!("{}\n", section.content))
+            });
+
+        self.create_version_tag(&version_str, changelog_section.as_deref().unwrap_or(""))?;
+
+        let current = previous_tag
+            .as_ref()
+            .map(|tag| HookVersion::new(&tag.to_string_with_prefix()));
+        let next_version = HookVersion::new(&version_str);
+        self.run_hooks(
+            HookType::PostBump,
+            current.as_ref(),
+            &next_version,
+            hooks_config,
+        )?;
+
+        info!("Recovered version: {}", version_str.green());
+
+        let version = match &SETTINGS.tag_prefix {
+            Some(prefix) => version_str
+                .strip_prefix(prefix.as_str())
+                .unwrap_or(&version_str),
+            None => &version_str,
+        };
+
+        Ok(ReleaseSummary {
+            version: version.to_string(),
+            previous_version: previous_tag.as_ref().map(Tag::to_string_with_prefix),
+            commits: self
+                .repository
+                .count_commits(previous_tag.as_ref().and_then(Tag::oid), &head.id())?,
+            tag: version_str,
+            changelog: changelog_section,
+        })
+    }
+
     pub fn get_changelog_at_tag(&self, tag: &str, template: Template) -> Result<String> {
         let pattern = format!("..{}", tag);
         let pattern = RevspecPattern::from(pattern.as_str());
@@ -567,6 +1466,47 @@ impl CocoGitto {
             .map_err(|err| anyhow!(err))
     }
 
+    /// Render the changelog of the unreleased commits, from the latest tag, or the first
+    /// commit, to HEAD using the configured template.
+    pub fn get_latest_changelog(&self) -> Result<String> {
+        let changelog = self.get_changelog(RevspecPattern::default(), false)?;
+        let template = SETTINGS.get_changelog_template()?;
+
+        changelog
+            .into_markdown(template)
+            .map_err(|err| anyhow!(err))
+    }
+
+    /// Describe what the dry run `summary` would release: the commits since the
+    /// previous version, or `from_ref`, and the rendered changelog section, nothing is written.
+    pub fn dry_run_summary(
+        &self,
+        summary: ReleaseSummary,
+        from_ref: Option<&str>,
+    ) -> Result<DryRunSummary<'_>> {
+        let head = self.repository.get_head_commit_oid()?;
+        let origin = match (from_ref, self.repository.get_latest_tag()) {
+            (Some(from_ref), _) => self.resolve_from_ref(from_ref, head)?.to_string(),
+            (None, Ok(tag)) => tag.oid_unchecked().to_string(),
+            (None, Err(TagError::NoTag)) => self.repository.get_first_commit()?.to_string(),
+            (None, Err(err)) => bail!("{}", err),
+        };
+        let head = head.to_string();
+        let pattern = RevspecPattern::from((origin.as_str(), head.as_str()));
+        let release = self.get_changelog_with_target_version(pattern, &summary.tag)?;
+
+        let template = SETTINGS.get_changelog_template()?;
+        let changelog = Renderer::try_new(template)?.render_release(&release)?;
+
+        Ok(DryRunSummary {
+            version: summary.version,
+            previous_version: summary.previous_version,
+            tag: summary.tag,
+            commits: release.commits,
+            changelog,
+        })
+    }
+
     /// Used for cog bump. the target version
     /// is not created yet when generating the changelog.
     pub fn get_changelog_with_target_version(
@@ -576,8 +1516,9 @@ impl CocoGitto {
     ) -> Result<Release> {
         let commit_range = self.repository.get_commit_range(&pattern)?;
 
-        let mut release = Release::from(commit_range);
+        let mut release = Release::try_from(commit_range)?;
         release.version = OidOf::Tag(Tag::new(target_version, None)?);
+        self.add_commit_stats(&mut release)?;
         Ok(release)
     }
 
@@ -589,15 +1530,75 @@ impl CocoGitto {
         pattern: RevspecPattern,
         with_child_releases: bool,
     ) -> Result<Release> {
-        if with_child_releases {
-            self.repository
-                .get_release_range(pattern)
-                .map_err(Into::into)
+        let mut release = if with_child_releases {
+            let release = self.repository.get_release_range(pattern)?;
+            Self::assemble_unreleased(release)
         } else {
             let commit_range = self.repository.get_commit_range(&pattern)?;
+            Release::try_from(commit_range)?
+        };
+
+        self.add_commit_stats(&mut release)?;
+        Ok(release)
+    }
 
-            Ok(Release::from(commit_range))
+    // Fill the diff stats of `release` commits, and of its previous releases,
+    // when `changelog.commit_stats` is enabled.
+    fn add_commit_stats(&self, release: &mut Release) -> Result<()> {
+        if !SETTINGS.changelog.commit_stats {
+            return Ok(());
         }
+
+        let mut diff_stats = DiffStats::new(&self.repository);
+        let mut release = Some(release);
+        while let Some(current) = release {
+            for commit in &mut current.commits {
+                let oid = Oid::from_str(&commit.commit.oid)?;
+                commit.stats = Some(diff_stats.stats(oid)?);
+            }
+
+            release = current.previous.as_deref_mut();
+        }
+
+        Ok(())
+    }
+
+    // The commits past the latest tag are the head of the release chain, keep them as the
+    // top `Unreleased` section or drop them according to `changelog.show_unreleased`.
+    // Without any tagged release there is nothing else to show and they are kept.
+    fn assemble_unreleased(release: Release) -> Release {
+        match release {
+            Release {
+                version: OidOf::Head(_) | OidOf::Other(_),
+                previous: Some(previous),
+                ..
+            } if !SETTINGS.changelog.show_unreleased => *previous,
+            release => release,
+        }
+    }
+
+    /// Only keep the commits of `release`, and of its previous releases, modifying
+    /// the file or directory at `path`.
+    pub fn retain_path(&self, release: &mut Release, path: &Path) -> Result<()> {
+        let mut path_filter = PathFilter::new(path);
+        let mut release = Some(release);
+        while let Some(current) = release {
+            let mut touching = vec![];
+            for commit in current.commits.drain(..) {
+                let git_commit = self
+                    .repository
+                    .git
+                    .find_commit(Oid::from_str(&commit.commit.oid)?)?;
+                if path_filter.touches(&git_commit)? {
+                    touching.push(commit);
+                }
+            }
+
+            current.commits = touching;
+            release = current.previous.as_deref_mut();
+        }
+
+        Ok(())
     }
 
     fn run_hooks(
@@ -639,6 +1640,35 @@ impl CocoGitto {
         Ok(())
     }
 
+    // Version tags are annotated, with the release changelog section as message unless
+    // `bump.tag_message_template` is set, or the version itself when the message is empty.
+    fn create_version_tag(&self, version: &str, changelog: &str) -> Result<()> {
+        let template = SETTINGS
+            .bump
+            .tag_message_template
+            .as_deref()
+            .unwrap_or("{{changelog}}");
+        let message = Self::tag_message(template, version, changelog)?;
+        let message = if message.trim().is_empty() {
+            version
+        } else {
+            &message
+        };
+
+        self.repository.create_tag(version, message)?;
+        Ok(())
+    }
+
+    fn tag_message(template: &str, version: &str, changelog: &str) -> Result<String> {
+        let mut context = tera::Context::new();
+        context.insert("version", version);
+        context.insert("date", &Utc::now().format("%Y-%m-%d").to_string());
+        context.insert("changelog", changelog.trim());
+
+        tera::Tera::one_off(template, &context, false)
+            .map_err(|err| anyhow!("failed to render tag message template\n\ncause: {}", err))
+    }
+
     fn prefix_version(version: String) -> String {
         if let Some(prefix) = SETTINGS.tag_prefix.as_ref() {
             if !version.starts_with(prefix) {
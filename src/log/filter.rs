@@ -2,6 +2,7 @@ use crate::conventional::commit::Commit;
 
 use conventional_commit_parser::commit::CommitType;
 use git2::Commit as Git2Commit;
+use std::path::{Path, PathBuf};
 
 #[derive(Eq, PartialEq)]
 pub enum CommitFilter {
@@ -10,6 +11,8 @@ pub enum CommitFilter {
     Author(String),
     BreakingChange,
     NoError,
+    /// Commits modifying a file or directory, see [`crate::git::path::PathFilter`]
+    Path(PathBuf),
 }
 
 pub struct CommitFilters(pub Vec<CommitFilter>);
@@ -19,6 +22,13 @@ impl CommitFilters {
         !self.0.contains(&CommitFilter::NoError)
     }
 
+    pub(crate) fn path(&self) -> Option<&Path> {
+        self.0.iter().find_map(|filter| match filter {
+            CommitFilter::Path(path) => Some(path.as_path()),
+            _ => None,
+        })
+    }
+
     pub(crate) fn filter_git2_commit(&self, commit: &Git2Commit) -> bool {
         // Author filters
         let authors: Vec<&String> = self
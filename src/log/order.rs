@@ -0,0 +1,21 @@
+use git2::Sort;
+
+/// Order of the commits listed by `cog log`, newest first in the revwalk order by default.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct LogOrder {
+    /// Show no parent before all of its children and avoid intermixing lines of history,
+    /// like `git log --topo-order`
+    pub topological: bool,
+    /// Show the oldest commits first
+    pub reverse: bool,
+}
+
+impl LogOrder {
+    pub(crate) fn sort(&self) -> Sort {
+        if self.topological {
+            Sort::TOPOLOGICAL
+        } else {
+            Sort::NONE
+        }
+    }
+}
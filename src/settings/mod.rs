@@ -1,21 +1,46 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::conventional::commit::CommitConfig;
 use crate::git::repository::Repository;
+use crate::git::tag::Tag;
 use crate::{CommitsMetadata, CONFIG_PATH, SETTINGS};
 
 use crate::conventional::changelog::error::ChangelogError;
-use crate::conventional::changelog::template::{RemoteContext, Template};
+use crate::conventional::changelog::template::{RemoteContext, Template, FLAT_TEMPLATE_NAME};
 use crate::settings::error::SettingError;
 use config::{Config, File};
 use conventional_commit_parser::commit::CommitType;
+use semver::Version;
 use serde::{Deserialize, Serialize};
 
 type CommitsMetadataSettings = HashMap<String, CommitConfig>;
+
+/// Categories of the Keep a Changelog format, in rendering order
+pub const KEEPACHANGELOG_CATEGORIES: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+const DEFAULT_KEEPACHANGELOG_CATEGORIES: [(&str, &str); 5] = [
+    ("feat", "Added"),
+    ("fix", "Fixed"),
+    ("perf", "Changed"),
+    ("refactor", "Changed"),
+    ("revert", "Removed"),
+];
 pub(crate) type AuthorSettings = Vec<AuthorSetting>;
 
 mod error;
+mod preset;
+mod validation;
+
+pub use preset::Preset;
+pub use validation::ConfigProblem;
 
 #[derive(Copy, Clone)]
 pub enum HookType {
@@ -30,6 +55,9 @@ pub struct Settings {
     pub ignore_merge_commits: bool,
     #[serde(default)]
     pub branch_whitelist: Vec<String>,
+    /// Branch pull requests are merged into, e.g. for `cog check --pr-base`.
+    /// Detected from the `origin/HEAD` reference when unset
+    pub default_branch: Option<String>,
     pub tag_prefix: Option<String>,
     #[serde(default)]
     pub pre_bump_hooks: Vec<String>,
@@ -38,31 +66,449 @@ pub struct Settings {
     #[serde(default)]
     pub commit_types: CommitsMetadataSettings,
     #[serde(default)]
+    pub commit: CommitSettings,
+    #[serde(default)]
     pub changelog: Changelog,
     #[serde(default)]
+    pub bump: BumpSettings,
+    #[serde(default)]
     pub bump_profiles: HashMap<String, BumpProfile>,
+    /// Short `cog` subcommands creating a commit of the given type, e.g. `{ f = "feat" }`
+    /// for `cog f "add a feature"`, equivalent to `cog commit feat "add a feature"`
+    #[serde(default)]
+    pub commit_aliases: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct CommitSettings {
+    /// Match commit types regardless of their case, `Feat`, `FEAT` and `feat` are equivalent.
+    /// Enabled unless set to `false`, which only accepts types written as configured
+    pub case_insensitive_types: Option<bool>,
+    /// Reject commits without a valid `Signed-off-by: Name <email>` trailer
+    pub require_signoff: bool,
+    /// Reject breaking changes without a body or a `BREAKING CHANGE:` description
+    pub require_breaking_body: bool,
+    /// Accept and keep a leading gitmoji, e.g. `:sparkles: feat: ...` or `✨ feat: ...`
+    pub gitmoji: bool,
+    /// Also parse historical commits using this separator instead of the colon,
+    /// e.g. `" - "` for `feat - description`. New commits must still use `type: description`.
+    pub legacy_separator: Option<String>,
+    /// Footer tokens flagging a breaking change in addition to `BREAKING CHANGE`,
+    /// e.g. `["CAMBIO IMPORTANTE"]`. The `!` marker is always recognized.
+    pub breaking_change_keywords: Vec<String>,
+    /// Commit types that must have a scope, e.g. `["feat", "fix"]`
+    pub scope_required_types: Vec<String>,
+    /// Commit types that must not have a scope
+    pub scope_forbidden_types: Vec<String>,
+    /// Regex matching a leading ticket reference, e.g. `"\\[[A-Z]+-\\d+\\]"` for
+    /// `[PROJ-123] feat: ...`. The ticket is stripped before parsing and kept for the changelog.
+    pub ticket_prefix_pattern: Option<String>,
+    /// Remove a trailing pull request reference, e.g. the ` (#42)` GitHub appends to squash
+    /// merged commits, from descriptions. The number is kept as `commit.pull_request` for templates
+    pub extract_trailing_pr: bool,
+    /// Handling of commit messages that are not valid UTF-8: `lossy` replaces invalid
+    /// sequences, `skip` leaves the commit out with a warning and `error` rejects it
+    pub on_invalid_utf8: InvalidUtf8Policy,
+    /// Case of the first letter of commit descriptions: `lower`, `sentence` for an
+    /// uppercase letter, or `any`
+    pub subject_case: SubjectCase,
+    /// Maximum number of characters of each commit body line, footers and lines
+    /// containing a URL are not checked. Unlimited when unset.
+    pub max_body_line_length: Option<usize>,
+    /// Whether a body line longer than `max_body_line_length` is an `error`
+    /// or only a `warning`
+    pub body_line_length_severity: Severity,
+    /// Make `cog check` report commits sharing the same subject within the checked range,
+    /// often left by a cherry-pick or a bad rebase
+    pub flag_duplicates: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct BumpSettings {
+    /// Version used as is for the first release of a repository without any version tag,
+    /// instead of incrementing `0.0.0`
+    pub initial_version: Option<String>,
+    /// Message of the annotated version tags, `{{version}}`, `{{date}}` and `{{changelog}}`
+    /// are replaced with the new version, the current date and the version changelog section.
+    /// Defaults to the version changelog section.
+    pub tag_message_template: Option<String>,
+    /// Promote an automatic patch bump to a minor bump once this many bug fixes accumulated
+    /// since the last minor version, `0` disables it
+    pub patch_to_minor_threshold: usize,
+    /// Subject of the version commit created by `cog bump`, `{{version}}` is replaced
+    /// with the new version tag. Defaults to `chore(version): {{version}}`.
+    /// Version commits are left out of changelogs and `cog log`.
+    pub commit_message_template: Option<String>,
+    /// Marker appended to the version commit subject by `cog bump --skip-ci` so CI
+    /// does not build it again, e.g. `[ci skip]`. Defaults to `[skip ci]`.
+    pub skip_ci: Option<String>,
+    /// Commit types worth a release, e.g. `["feat", "fix", "perf"]`. `cog bump --auto` does
+    /// not release a range without any of them nor breaking changes, other listed types
+    /// than `feat` and `fix` trigger a patch release. Every commit type is considered when empty.
+    pub release_commit_types: Vec<String>,
+    /// Outcome of `cog bump --auto` without any commit to release: `skip` exits successfully
+    /// with a message, `error` fails
+    pub empty_release: EmptyRelease,
+    /// Regex extracting the version of tags in a non-standard format through its `version`
+    /// capture group, e.g. `"^v(?P<version>.+)-company$"`. Non-matching tags are ignored.
+    /// Tags created by `cog bump` are still named `<tag_prefix><version>` and must match it
+    pub tag_pattern: Option<String>,
+    /// Name of the version tags, `{version}` is replaced with the version and `{scope}` with
+    /// the package given to `--package`, e.g. `"{scope}@{version}"` for npm-style `api@1.2.0`
+    /// tags. Each package then has its own versions. Replaces `tag_prefix`
+    pub tag_format: Option<String>,
+    /// Fail `cog bump` when its version commit message is not a valid conventional commit,
+    /// e.g. with a custom `commit_message_template` or `cog bump --message`
+    pub verify_bump_commit: bool,
+}
+
+impl CommitSettings {
+    /// Whether commit types match regardless of their case, see `case_insensitive_types`
+    pub fn case_insensitive_types(&self) -> bool {
+        self.case_insensitive_types != Some(false)
+    }
+}
+
+impl BumpSettings {
+    const DEFAULT_COMMIT_MESSAGE_TEMPLATE: &'static str = "chore(version): {{version}}";
+    const DEFAULT_SKIP_CI: &'static str = "[skip ci]";
+
+    fn commit_message_template(&self) -> &str {
+        self.commit_message_template
+            .as_deref()
+            .unwrap_or(Self::DEFAULT_COMMIT_MESSAGE_TEMPLATE)
+    }
+
+    fn skip_ci(&self) -> &str {
+        self.skip_ci.as_deref().unwrap_or(Self::DEFAULT_SKIP_CI)
+    }
+
+    /// Whether version tags are scoped to a package, see `tag_format`
+    pub(crate) fn has_scoped_tags(&self) -> bool {
+        self.tag_format
+            .as_deref()
+            .is_some_and(|format| format.contains("{scope}"))
+    }
+
+    /// The version tag of `version` for the package `scope`, following `tag_format`
+    /// or prefixed with `tag_prefix`
+    pub(crate) fn tag_name(&self, version: &Version, scope: Option<&str>) -> String {
+        match (self.tag_format.as_deref(), SETTINGS.tag_prefix.as_deref()) {
+            (Some(format), _) => format
+                .replace("{scope}", scope.unwrap_or(""))
+                .replace("{version}", &version.to_string()),
+            (None, Some(prefix)) => format!("{}{}", prefix, version),
+            (None, None) => version.to_string(),
+        }
+    }
+
+    /// The version commit message for the given version tag
+    pub fn commit_message(&self, version: &str) -> String {
+        self.commit_message_template()
+            .replace("{{version}}", version)
+    }
+
+    /// The version commit `message` followed by the skip CI marker
+    pub fn with_skip_ci(&self, message: &str) -> String {
+        format!("{} {}", message, self.skip_ci())
+    }
+
+    /// Whether `message` is a version commit created by `cog bump`
+    pub(crate) fn is_version_commit(&self, message: &str) -> bool {
+        let template = self.commit_message_template();
+        if template.contains("{{version}}") {
+            self.commit_version(message).is_some()
+        } else {
+            self.commit_headers(message)
+                .any(|header| header == template)
+        }
+    }
+
+    /// The version tag of a version commit created by `cog bump`, `None` for other commits
+    pub(crate) fn commit_version<'a>(&self, message: &'a str) -> Option<&'a str> {
+        let (prefix, suffix) = self.commit_message_template().split_once("{{version}}")?;
+        let tag_prefix = SETTINGS.tag_prefix.as_deref().unwrap_or("");
+
+        self.commit_headers(message).find_map(|header| {
+            let version = header.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            if self.tag_format.is_some() {
+                Tag::new(version, None).ok()?.to_version().ok()?;
+            } else {
+                Version::parse(version.strip_prefix(tag_prefix).unwrap_or(version)).ok()?;
+            }
+            Some(version)
+        })
+    }
+
+    // The commit subject, as is and without the skip CI marker `cog bump --skip-ci` may have added
+    fn commit_headers<'a>(&self, message: &'a str) -> impl Iterator<Item = &'a str> {
+        let header = message.lines().next().unwrap_or("").trim_end();
+        let without_skip_ci = header.strip_suffix(self.skip_ci()).map(str::trim_end);
+        std::iter::once(header).chain(without_skip_ci)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 #[serde(deny_unknown_fields, default)]
 pub struct Changelog {
+    /// Text kept at the top of the changelog file, above all version sections
+    pub header: Option<String>,
+    /// Text kept at the bottom of the changelog file, below all version sections
+    pub footer: Option<String>,
     pub template: Option<String>,
+    /// Render each release as a single list of commits, without commit type sections.
+    /// Shorthand for `template = "flat"`
+    pub flat: bool,
     pub remote: Option<String>,
     pub path: PathBuf,
     pub owner: Option<String>,
     pub repository: Option<String>,
     pub authors: AuthorSettings,
+    /// Append the list of contributors to each release section
+    pub contributors: bool,
+    pub contributors_order: ContributorsOrder,
+    /// Also credit the `Co-authored-by` trailers of each commit in the contributors list
+    pub count_coauthors: bool,
+    /// Also list the commit types sections without any commit in a release
+    pub show_empty_sections: bool,
+    /// Text rendered for a release without any commit, e.g. "No notable changes"
+    pub no_changes_placeholder: Option<String>,
+    /// What to do with conventional commits of an unknown type: `skip`, `error` or `section:<title>`
+    pub unknown_type_policy: UnknownTypePolicy,
+    /// Turn `#123` and `GH-123` references in commit descriptions into links
+    pub link_issues: bool,
+    /// Issue url used by `link_issues`, `{{id}}` is replaced with the issue number.
+    /// Defaults to `<repository_url>/issues/{{id}}` when the remote is configured
+    pub issue_url_template: Option<String>,
+    /// Only keep the newest release in the changelog file, overwriting the previous ones on bump
+    pub latest_only: bool,
+    /// Render full commit hashes instead of the abbreviated ones
+    pub full_hash: bool,
+    /// Rendering of commit authors without a username: `name`, `email` or `name_email`
+    pub author_display: AuthorDisplay,
+    /// Line ending of the changelog file: `auto` keeps the dominant one of the existing file,
+    /// `lf` or `crlf` force it
+    pub line_ending: LineEnding,
+    /// List breaking changes before the other commits of their section
+    pub breaking_first: bool,
+    /// Append a `With thanks to ...` line listing the `Co-authored-by` and `Reviewed-by`
+    /// trailers of each release
+    pub thanks: bool,
+    /// Prefix each release section with a stable html anchor derived from its version,
+    /// e.g. `<a id="1-2-0"></a>`
+    pub anchors: bool,
+    /// Read the conventional message from the body of GitHub `Merge pull request #<id>` commits
+    /// instead of ignoring them, the pull request number is available to templates
+    pub parse_merge_pr: bool,
+    /// Render the commits since the latest tag as an `Unreleased` section on top of the
+    /// full changelog
+    pub show_unreleased: bool,
+    /// Commit authors left out of the changelog, by `Name <email>`, name or email,
+    /// e.g. `["dependabot[bot]"]`. `cog log` still lists their commits
+    pub exclude_authors: Vec<String>,
+    /// Append a `(Closes #1, #2)` note to the summary of commits with closing footers,
+    /// e.g. `Closes: #1, #2` or `Fixes #3`
+    pub closes_note: bool,
+    /// Append the files changed, insertions and deletions of each commit to its changelog
+    /// entry. Each commit is diffed against its parent, expect slower changelog generation
+    pub commit_stats: bool,
+    /// Order of the commits within a changelog section: `date` (newest first), `scope`
+    /// or `alpha` on the commit description. Unset keeps the git history order.
+    pub within_section_sort: Option<WithinSectionSort>,
+    /// Changelog section titles by commit type, overriding the built-in and `commit_types` ones,
+    /// e.g. `{ feat = "New Features", fix = "Fixes" }`
+    pub titles: HashMap<String, String>,
+    /// Changelog sections of commit types rendered along with another type, by section title
+    /// or commit type, e.g. `{ perf = "feat", refactor = "Internal" }`. Applied after `titles`
+    pub section_merge: HashMap<String, String>,
+    /// Categories of the commit types in the `keepachangelog` template, e.g.
+    /// `{ perf = "Changed", deprecate = "Deprecated" }`. Extends the built-in mapping of
+    /// `feat` to `Added`, `fix` to `Fixed`, `perf` and `refactor` to `Changed` and `revert`
+    /// to `Removed`, commit types of neither are left out
+    pub keepachangelog_categories: HashMap<String, String>,
+    /// Usernames of the commit authors by `Name <email>` or `Name` signature,
+    /// e.g. `{ "Jane Doe <jane@example.org>" = "janedoe" }`.
+    /// Authors listed in `authors` take precedence.
+    pub author_handles: HashMap<String, String>,
+}
+
+/// Ordering of the changelog contributors list
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ContributorsOrder {
+    /// Most active contributors first
+    #[default]
+    Commits,
+    /// Alphabetical order
+    Name,
+}
+
+/// Rendering of the changelog commit authors
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorDisplay {
+    /// `Jane Doe`
+    #[default]
+    Name,
+    /// `jane@example.org`, the name when the email is unknown
+    Email,
+    /// `Jane Doe <jane@example.org>`, the name when the email is unknown
+    NameEmail,
+}
+
+/// Ordering of the commits within a changelog section
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WithinSectionSort {
+    /// Newest commits first
+    Date,
+    /// Grouped by scope in alphabetical order, newest first within a scope
+    Scope,
+    /// Alphabetical order of the commit descriptions
+    Alpha,
+}
+
+/// Line ending used when writing the changelog file
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    /// Keep the dominant line ending of the existing file, `\n` for a new file
+    #[default]
+    Auto,
+    Lf,
+    Crlf,
+}
+
+/// Handling of commit messages that are not valid UTF-8, e.g. latin-1 encoded legacy commits
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum InvalidUtf8Policy {
+    /// Replace invalid sequences with `U+FFFD`
+    #[default]
+    Lossy,
+    /// Leave the commit out with a warning
+    Skip,
+    /// Report the commit as errored
+    Error,
+}
+
+/// Required case of the first letter of commit descriptions
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SubjectCase {
+    /// No constraint
+    #[default]
+    Any,
+    /// `feat: add a feature`
+    Lower,
+    /// `feat: Add a feature`
+    Sentence,
+}
+
+/// Outcome of an automatic bump without any commit to release
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EmptyRelease {
+    /// Nothing is released and the bump succeeds
+    #[default]
+    Skip,
+    /// The bump fails
+    Error,
+}
+
+/// Outcome of a failed commit style rule
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The commit is rejected
+    #[default]
+    Error,
+    /// A warning is printed and the commit is accepted
+    Warning,
+}
+
+/// Changelog handling of commits whose type is not part of the configured commit types
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum UnknownTypePolicy {
+    /// Leave the commit out of the changelog with a warning
+    #[default]
+    Skip,
+    /// Fail the changelog generation
+    Error,
+    /// Group the commits under a changelog section with the given title
+    Section(String),
+}
+
+impl TryFrom<String> for UnknownTypePolicy {
+    type Error = String;
+
+    fn try_from(policy: String) -> Result<Self, String> {
+        match policy.as_str() {
+            "skip" => Ok(UnknownTypePolicy::Skip),
+            "error" => Ok(UnknownTypePolicy::Error),
+            _ => match policy.strip_prefix("section:") {
+                Some(title) if !title.trim().is_empty() => {
+                    Ok(UnknownTypePolicy::Section(title.trim().to_string()))
+                }
+                _ => Err(format!(
+                    "invalid unknown_type_policy '{}', expected 'skip', 'error' or 'section:<title>'",
+                    policy
+                )),
+            },
+        }
+    }
+}
+
+impl From<UnknownTypePolicy> for String {
+    fn from(policy: UnknownTypePolicy) -> Self {
+        match policy {
+            UnknownTypePolicy::Skip => "skip".to_string(),
+            UnknownTypePolicy::Error => "error".to_string(),
+            UnknownTypePolicy::Section(title) => format!("section:{}", title),
+        }
+    }
 }
 
 impl Default for Changelog {
     fn default() -> Self {
         Changelog {
+            header: None,
+            footer: None,
             template: None,
+            flat: false,
             remote: None,
             path: PathBuf::from("CHANGELOG.md"),
             owner: None,
             repository: None,
             authors: vec![],
+            contributors: false,
+            contributors_order: ContributorsOrder::default(),
+            count_coauthors: false,
+            show_empty_sections: false,
+            no_changes_placeholder: None,
+            unknown_type_policy: UnknownTypePolicy::default(),
+            link_issues: false,
+            issue_url_template: None,
+            latest_only: false,
+            full_hash: false,
+            author_display: AuthorDisplay::default(),
+            line_ending: LineEnding::default(),
+            breaking_first: false,
+            thanks: false,
+            anchors: false,
+            parse_merge_pr: false,
+            show_unreleased: true,
+            exclude_authors: vec![],
+            closes_note: false,
+            commit_stats: false,
+            within_section_sort: None,
+            titles: HashMap::new(),
+            section_merge: HashMap::new(),
+            keepachangelog_categories: HashMap::new(),
+            author_handles: HashMap::new(),
         }
     }
 }
@@ -74,13 +520,31 @@ pub struct AuthorSetting {
     pub username: String,
 }
 
-pub fn commit_username(author: &str) -> Option<&'static str> {
-    SETTINGS
-        .changelog
+/// User-global settings shared by every repository, e.g. author mappings, at
+/// `$XDG_CONFIG_HOME/cocogitto/cog.toml` or `~/.config/cocogitto/cog.toml`
+pub fn global_config_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("cocogitto").join(CONFIG_PATH))
+}
+
+pub fn commit_username(author: &str, email: Option<&str>) -> Option<&'static str> {
+    let changelog = &SETTINGS.changelog;
+    let username = changelog
         .authors
         .iter()
         .find(|author_map| author_map.signature == author)
-        .map(|author| author.username.as_str())
+        .map(|author| author.username.as_str());
+
+    username
+        .or_else(|| {
+            let signature = format!("{} <{}>", author, email?);
+            changelog.author_handles.get(&signature).map(String::as_str)
+        })
+        .or_else(|| changelog.author_handles.get(author).map(String::as_str))
 }
 
 pub fn changelog_path() -> &'static PathBuf {
@@ -100,23 +564,43 @@ impl Settings {
     // Fails only if config exists and is malformed
     pub(crate) fn get(repository: &Repository) -> Result<Self, SettingError> {
         match repository.get_repo_dir() {
-            Some(repo_path) => {
-                let settings_path = repo_path.join(CONFIG_PATH);
-                if settings_path.exists() {
-                    Config::builder()
-                        .add_source(File::from(settings_path))
-                        .build()
-                        .map_err(SettingError::from)?
-                        .try_deserialize()
-                        .map_err(SettingError::from)
-                } else {
-                    Ok(Settings::default())
-                }
-            }
+            Some(repo_path) => Settings::from_file(&repo_path.join(CONFIG_PATH)),
             None => Ok(Settings::default()),
         }
     }
 
+    // Fails only if config exists and is malformed
+    pub(crate) fn from_file(settings_path: &Path) -> Result<Self, SettingError> {
+        Settings::from_files(global_config_path().as_deref(), settings_path)
+    }
+
+    // The `settings_path` settings merged over the user-global ones, keys set in both
+    // files take the `settings_path` value
+    fn from_files(global_path: Option<&Path>, settings_path: &Path) -> Result<Self, SettingError> {
+        let paths: Vec<&Path> = [global_path, Some(settings_path)]
+            .into_iter()
+            .flatten()
+            .filter(|path| path.exists())
+            .collect();
+
+        if paths.is_empty() {
+            return Ok(Settings::default());
+        }
+
+        let settings: Settings = paths
+            .into_iter()
+            .fold(Config::builder(), |builder, path| {
+                builder.add_source(File::from(path))
+            })
+            .build()
+            .map_err(SettingError::from)?
+            .try_deserialize()
+            .map_err(SettingError::from)?;
+
+        settings.check_patterns()?;
+        Ok(settings)
+    }
+
     pub fn commit_types(&self) -> CommitsMetadata {
         let commit_settings = self.commit_types.clone();
         let mut custom_types = HashMap::new();
@@ -129,6 +613,32 @@ impl Settings {
 
         default_types.extend(custom_types);
 
+        for (commit_type, title) in &self.changelog.titles {
+            if let Some(config) = default_types.get_mut(&CommitType::from(commit_type.as_str())) {
+                config.changelog_title = title.clone();
+            }
+        }
+
+        // Resolve every section before routing so merges do not chain
+        let sections: Vec<(CommitType, String)> = self
+            .changelog
+            .section_merge
+            .iter()
+            .map(|(commit_type, section)| {
+                let title = default_types
+                    .get(&CommitType::from(section.as_str()))
+                    .map(|config| config.changelog_title.clone())
+                    .unwrap_or_else(|| section.clone());
+                (CommitType::from(commit_type.as_str()), title)
+            })
+            .collect();
+
+        for (commit_type, title) in sections {
+            if let Some(config) = default_types.get_mut(&commit_type) {
+                config.changelog_title = title;
+            }
+        }
+
         default_types
     }
 
@@ -182,9 +692,37 @@ impl Settings {
         RemoteContext::try_new(remote, repository, owner)
     }
 
+    /// Issue url template used to link issue references, `None` unless `link_issues` is enabled
+    pub fn get_issue_url_template(&self) -> Option<String> {
+        if !self.changelog.link_issues {
+            return None;
+        }
+
+        self.changelog.issue_url_template.clone().or_else(|| {
+            self.get_template_context()
+                .map(|context| format!("{}/issues/{{{{id}}}}", context.repository_url()))
+        })
+    }
+
+    /// Keep a Changelog category of `commit_type`, see `changelog.keepachangelog_categories`
+    pub fn keepachangelog_category(&self, commit_type: &CommitType) -> Option<&str> {
+        let commit_type = commit_type.to_string();
+        match self.changelog.keepachangelog_categories.get(&commit_type) {
+            Some(category) => Some(category.as_str()),
+            None => DEFAULT_KEEPACHANGELOG_CATEGORIES
+                .iter()
+                .find(|(default_type, _)| *default_type == commit_type)
+                .map(|(_, category)| *category),
+        }
+    }
+
     pub fn get_changelog_template(&self) -> Result<Template, ChangelogError> {
         let context = self.get_template_context();
-        let template = self.changelog.template.as_deref().unwrap_or("default");
+        let template = if self.changelog.flat {
+            FLAT_TEMPLATE_NAME
+        } else {
+            self.changelog.template.as_deref().unwrap_or("default")
+        };
 
         Template::from_arg(template, context)
     }
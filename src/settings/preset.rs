@@ -0,0 +1,87 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+const MINIMAL_PRESET: &str = include_str!("presets/minimal.toml");
+const MONOREPO_PRESET: &str = include_str!("presets/monorepo.toml");
+const GITMOJI_PRESET: &str = include_str!("presets/gitmoji.toml");
+
+/// A `cog.toml` variant written by `cog init --template`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// A short config with the changelog essentials
+    Minimal,
+    /// Scoped commits and per release contributors for repositories hosting several packages
+    Monorepo,
+    /// Gitmoji prefixed commits
+    Gitmoji,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 3] = [Preset::Minimal, Preset::Monorepo, Preset::Gitmoji];
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Preset::Minimal => "minimal",
+            Preset::Monorepo => "monorepo",
+            Preset::Gitmoji => "gitmoji",
+        }
+    }
+
+    /// The `cog.toml` content of this preset
+    pub const fn content(&self) -> &'static str {
+        match self {
+            Preset::Minimal => MINIMAL_PRESET,
+            Preset::Monorepo => MONOREPO_PRESET,
+            Preset::Gitmoji => GITMOJI_PRESET,
+        }
+    }
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Preset::ALL
+            .into_iter()
+            .find(|preset| preset.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Preset::ALL.iter().map(Preset::name).collect();
+                format!(
+                    "unknown preset '{}', expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::settings::preset::Preset;
+    use crate::settings::Settings;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_parse_all_presets() {
+        for preset in Preset::ALL {
+            // Act
+            let settings = toml::from_str::<Settings>(preset.content());
+
+            // Assert
+            assert_that!(settings).is_ok();
+            assert_that!(settings.unwrap().problems()).is_empty();
+        }
+    }
+
+    #[test]
+    fn should_get_preset_from_name() {
+        assert_that!("monorepo".parse::<Preset>()).is_equal_to(Ok(Preset::Monorepo));
+        assert_that!("unknown".parse::<Preset>()).is_err();
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+
+use config::ConfigError;
+use conventional_commit_parser::commit::CommitType;
+use globset::Glob;
+use regex::Regex;
+use semver::Version;
+use tera::Tera;
+
+use crate::conventional::changelog::renderer::Renderer;
+use crate::conventional::changelog::template::{RemoteContext, Template, REMOTE_TEMPLATE_NAME};
+use crate::settings::error::SettingError;
+use crate::settings::{Settings, KEEPACHANGELOG_CATEGORIES};
+
+/// A problem found in a `cog.toml` file by [`Settings::validate_file`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// 1-based line of the offending key, if it could be located
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Display for ConfigProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Settings {
+    /// Parse and check the config file at `path`, returning every problem found
+    /// instead of failing on the first one. Fails only if the file cannot be read.
+    pub fn validate_file(path: &Path) -> io::Result<Vec<ConfigProblem>> {
+        let content = std::fs::read_to_string(path)?;
+
+        let settings: Settings = match toml::from_str(&content) {
+            Ok(settings) => settings,
+            Err(err) => {
+                return Ok(vec![ConfigProblem {
+                    line: err.line_col().map(|(line, _)| line + 1),
+                    message: err.to_string(),
+                }])
+            }
+        };
+
+        let problems = settings
+            .problems()
+            .into_iter()
+            .map(|(key, message)| ConfigProblem {
+                line: line_of(&content, key),
+                message: format!("`{}`: {}", key, message),
+            })
+            .collect();
+
+        Ok(problems)
+    }
+
+    // The regexes are compiled where they are used, reject invalid ones when loading the
+    // settings rather than silently ignoring them
+    pub(super) fn check_patterns(&self) -> Result<(), SettingError> {
+        let patterns = [
+            ("bump.tag_pattern", &self.bump.tag_pattern),
+            (
+                "commit.ticket_prefix_pattern",
+                &self.commit.ticket_prefix_pattern,
+            ),
+        ];
+
+        for (key, pattern) in patterns {
+            if let Some(pattern) = pattern {
+                Regex::new(pattern).map_err(|err| {
+                    ConfigError::Message(format!("invalid `{}` regex: {}", key, err))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Semantic checks on an already deserialized config, as `(key, message)` pairs
+    pub(super) fn problems(&self) -> Vec<(&str, String)> {
+        let mut problems = vec![];
+
+        for pattern in &self.branch_whitelist {
+            if let Err(err) = Glob::new(pattern) {
+                problems.push(("branch_whitelist", format!("invalid glob pattern: {}", err)));
+            }
+        }
+
+        for (commit_type, config) in &self.commit_types {
+            if commit_type.is_empty()
+                || commit_type.contains(|c: char| c.is_whitespace() || "()!:".contains(c))
+            {
+                problems.push((
+                    commit_type.as_str(),
+                    "commit type must be a single word without `()!:`".to_string(),
+                ));
+            }
+
+            if config.changelog_title.trim().is_empty() {
+                problems.push((
+                    commit_type.as_str(),
+                    "changelog_title must not be empty".to_string(),
+                ));
+            }
+        }
+
+        let commit_types = self.commit_types();
+        for commit_type in self.changelog.titles.keys() {
+            if !commit_types.contains_key(&CommitType::from(commit_type.as_str())) {
+                problems.push((
+                    commit_type.as_str(),
+                    "changelog title defined for an unknown commit type".to_string(),
+                ));
+            }
+        }
+
+        for commit_type in self.changelog.section_merge.keys() {
+            if !commit_types.contains_key(&CommitType::from(commit_type.as_str())) {
+                problems.push((
+                    commit_type.as_str(),
+                    "changelog section merge defined for an unknown commit type".to_string(),
+                ));
+            }
+        }
+
+        for (commit_type, category) in &self.changelog.keepachangelog_categories {
+            if !commit_types.contains_key(&CommitType::from(commit_type.as_str())) {
+                problems.push((
+                    commit_type.as_str(),
+                    "Keep a Changelog category defined for an unknown commit type".to_string(),
+                ));
+            } else if !KEEPACHANGELOG_CATEGORIES.contains(&category.as_str()) {
+                problems.push((
+                    commit_type.as_str(),
+                    format!(
+                        "unknown Keep a Changelog category `{}`, expected one of {}",
+                        category,
+                        KEEPACHANGELOG_CATEGORIES.join(", ")
+                    ),
+                ));
+            }
+        }
+
+        for (alias, commit_type) in &self.commit_aliases {
+            if !commit_types.contains_key(&CommitType::from(commit_type.as_str())) {
+                problems.push((
+                    alias.as_str(),
+                    format!("alias of the unknown commit type `{}`", commit_type),
+                ));
+            }
+        }
+
+        if let Some(pattern) = &self.commit.ticket_prefix_pattern {
+            if let Err(err) = Regex::new(pattern) {
+                problems.push(("ticket_prefix_pattern", format!("invalid regex: {}", err)));
+            }
+        }
+
+        if let Some(pattern) = &self.bump.tag_pattern {
+            match Regex::new(pattern) {
+                Err(err) => problems.push(("tag_pattern", format!("invalid regex: {}", err))),
+                Ok(regex) if !regex.capture_names().any(|name| name == Some("version")) => problems
+                    .push((
+                        "tag_pattern",
+                        "missing a `version` capture group".to_string(),
+                    )),
+                Ok(_) => {}
+            }
+        }
+
+        if let Some(format) = &self.bump.tag_format {
+            if !format.contains("{version}") {
+                problems.push((
+                    "tag_format",
+                    "missing a `{version}` placeholder".to_string(),
+                ));
+            }
+            if self.tag_prefix.is_some() || self.bump.tag_pattern.is_some() {
+                problems.push((
+                    "tag_format",
+                    "cannot be combined with `tag_prefix` nor `bump.tag_pattern`".to_string(),
+                ));
+            }
+        }
+
+        if self.commit.max_body_line_length == Some(0) {
+            problems.push(("max_body_line_length", "must be greater than 0".to_string()));
+        }
+
+        if let Some(initial_version) = &self.bump.initial_version {
+            if let Err(err) = Version::parse(initial_version) {
+                problems.push(("initial_version", format!("invalid version: {}", err)));
+            }
+        }
+
+        if let Some(template) = &self.bump.tag_message_template {
+            if let Err(err) = Tera::default().add_raw_template("tag_message", template) {
+                problems.push(("tag_message_template", format!("invalid template: {}", err)));
+            }
+        }
+
+        let changelog = &self.changelog;
+        let remote_context = [&changelog.remote, &changelog.owner, &changelog.repository];
+        let remote_context_set = remote_context
+            .iter()
+            .filter(|value| value.is_some())
+            .count();
+        if remote_context_set != 0 && remote_context_set != remote_context.len() {
+            problems.push((
+                "remote",
+                "`remote`, `owner` and `repository` must be set together".to_string(),
+            ));
+        }
+
+        if let Some(template) = &changelog.template {
+            let context = if remote_context_set == remote_context.len() {
+                RemoteContext::try_new(
+                    changelog.remote.clone(),
+                    changelog.repository.clone(),
+                    changelog.owner.clone(),
+                )
+            } else {
+                None
+            };
+
+            if template == REMOTE_TEMPLATE_NAME && context.is_none() {
+                problems.push((
+                    "template",
+                    "the remote template requires `remote`, `owner` and `repository`".to_string(),
+                ));
+            }
+
+            match Template::from_arg(template, context) {
+                Ok(template) => {
+                    if let Err(err) = Renderer::try_new(template) {
+                        problems.push(("template", format!("invalid template: {}", err)));
+                    }
+                }
+                Err(err) => problems.push(("template", err.to_string().trim().to_string())),
+            }
+
+            if changelog.flat {
+                problems.push((
+                    "flat",
+                    "cannot be combined with a changelog `template`".to_string(),
+                ));
+            }
+        }
+
+        if let Some(template) = &changelog.issue_url_template {
+            if !template.contains("{{id}}") {
+                problems.push((
+                    "issue_url_template",
+                    "must contain the `{{id}}` placeholder".to_string(),
+                ));
+            }
+        } else if changelog.link_issues && remote_context_set == 0 {
+            problems.push((
+                "link_issues",
+                "requires either `issue_url_template` or the changelog remote".to_string(),
+            ));
+        }
+
+        problems
+    }
+}
+
+// 1-based line of the first `key = ...` assignment or `[key]` table header.
+fn line_of(content: &str, key: &str) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| {
+            let line = line.trim_start().trim_start_matches('[');
+            let line = line.trim_start_matches('"');
+            match line.strip_prefix(key) {
+                Some(rest) => rest
+                    .trim_start_matches('"')
+                    .starts_with(|c: char| c.is_whitespace() || c == '=' || c == ']'),
+                None => false,
+            }
+        })
+        .map(|idx| idx + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::settings::validation::line_of;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_find_key_line() {
+        let content = "tag_prefix = \"v\"\n\n[changelog]\ntemplate = \"remote\"\n";
+
+        assert_that!(line_of(content, "template")).is_equal_to(Some(4));
+        assert_that!(line_of(content, "changelog")).is_equal_to(Some(3));
+        assert_that!(line_of(content, "tag")).is_none();
+    }
+}
@@ -0,0 +1,90 @@
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use cmd_lib::{run_cmd, run_fun};
+use sealed_test::prelude::*;
+use speculoos::prelude::*;
+use std::process::Command;
+
+#[sealed_test]
+fn amend_head_scope() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat(apu): add an endpoint\n\nwith a body")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("amend-scope")
+        .arg("api")
+        .assert()
+        .success();
+
+    // Assert
+    assert_that!(run_fun!(git log --format=%B -n 1)?)
+        .is_equal_to("feat(api): add an endpoint\n\nwith a body".to_string());
+    assert_that!(run_fun!(git rev-list --count HEAD)?).is_equal_to("2".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn amend_head_type() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat(parser): handle empty input")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("amend-type")
+        .arg("fix")
+        .assert()
+        .success();
+
+    // Assert
+    assert_that!(git_log_head()?.trim()).is_equal_to("fix(parser): handle empty input");
+    Ok(())
+}
+
+#[sealed_test]
+fn amend_head_with_unknown_type_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let head = git_commit("feat: a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("amend-type")
+        .arg("wip")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_that!(run_fun!(git rev-parse HEAD)?).is_equal_to(head);
+    Ok(())
+}
+
+#[sealed_test]
+fn amend_pushed_head_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let head = git_commit("feat(apu): a feature")?;
+    run_cmd!(git update-ref refs/remotes/origin/master HEAD;)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("amend-scope")
+        .arg("api")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "already been pushed to origin/master",
+        ));
+
+    assert_that!(run_fun!(git rev-parse HEAD)?).is_equal_to(head);
+    Ok(())
+}
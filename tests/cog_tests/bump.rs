@@ -4,7 +4,9 @@ use crate::helpers::*;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
+use cmd_lib::{run_cmd, run_fun};
 use indoc::indoc;
+use predicates::prelude::predicate;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
 use std::path::Path;
@@ -73,6 +75,236 @@ fn auto_bump_dry_run_from_latest_tag() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn auto_bump_counts_breaking_change_without_body() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[commit]\nrequire_breaking_body = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat!: drop the legacy api")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("2.0.0");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_counts_commit_with_long_body_line() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "[commit]\nmax_body_line_length = 20\nbody_line_length_severity = \"error\"",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add a feature\n\nthis body line is way longer than twenty characters")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_counts_commit_with_wrong_subject_case() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[commit]\nsubject_case = \"lower\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: Add a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_counts_commit_missing_required_scope() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[commit]\nscope_required_types = [\"feat\"]", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_counts_commit_without_signoff() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[commit]\nrequire_signoff = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_counts_merge_pull_request_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[changelog]\nparse_merge_pr = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    run_cmd!(git checkout -q -b feature;)?;
+    git_commit("fix: work in progress")?;
+    run_cmd!(
+        git checkout -q -;
+        git merge -q --no-ff feature -m "Merge pull request #42 from org/feature" -m "feat(api): add the users endpoint";
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).contains("add the users endpoint");
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_ignores_merge_pull_request_commits_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    run_cmd!(git checkout -q -b feature;)?;
+    git_commit("fix: work in progress")?;
+    run_cmd!(
+        git checkout -q -;
+        git merge -q --no-ff feature -m "Merge pull request #42 from org/feature" -m "feat(api): add the users endpoint";
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_dry_run_with_json_format() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feature = git_commit("feat(api): feature")?;
+    let fix = git_commit("fix: bug fix")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .success();
+
+    // Assert
+    let output = String::from_utf8_lossy(&output.get_output().stdout).to_string();
+    let summary: serde_json::Value = serde_json::from_str(&output)?;
+    assert_that!(summary["version"]).is_equal_to(serde_json::json!("1.1.0"));
+    assert_that!(summary["previous_version"]).is_equal_to(serde_json::json!("1.0.0"));
+    assert_that!(summary["tag"]).is_equal_to(serde_json::json!("1.1.0"));
+
+    let commit_ids: Vec<&str> = summary["commits"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|commit| commit["id"].as_str().unwrap())
+        .collect();
+    assert_that!(commit_ids).contains_all_of(&vec![&fix.as_str(), &feature.as_str()]);
+    assert_that!(summary["commits"][0]["summary"].as_str()).is_some();
+
+    let changelog = summary["changelog"].as_str().unwrap();
+    assert_that!(changelog).contains("## 1.1.0");
+    assert_that!(changelog).contains("bug fix");
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_format_requires_dry_run() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
 #[sealed_test]
 fn auto_bump_major_from_latest_tag() -> Result<()> {
     git_init()?;
@@ -301,3 +533,1051 @@ fn bump_with_profile_hook() -> Result<()> {
     assert_tag_exists("1.0.1")?;
     Ok(())
 }
+
+#[sealed_test]
+fn bump_with_scoped_tag_format() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    let config = indoc! {
+        "[bump]
+            tag_format = \"{scope}@{version}\"
+
+            [bump_profiles.api]
+            post_bump_hooks = [ \"echo next {{version}}\" ]
+
+            [bump_profiles.web]
+        "
+    };
+
+    git_add(config, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("api@1.2.0")?;
+    git_tag("web@2.0.0")?;
+    git_commit("feat(api): feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("api")
+        .arg("--hook-profile")
+        .arg("api")
+        // Assert
+        .assert()
+        .success()
+        .stdout("next 1.3.0\n")
+        .stderr(predicate::str::contains("api@1.2.0 -> api@1.3.0"));
+
+    assert_tag_exists("api@1.3.0")?;
+    assert_tag_does_not_exist("web@2.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_scoped_tag_format_requires_package() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[bump]\ntag_format = \"{scope}@{version}\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("api@1.2.0")?;
+    git_commit("feat(api): feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "select the package with `--package`",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_package_without_scoped_tag_format_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("api")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "`--package` requires `{scope}` in `bump.tag_format`",
+        ));
+
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_changelog_header() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(
+        "cog.toml",
+        "[changelog]\nheader = \"# My changelog\\nA stable preamble\"",
+    )?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).starts_with("# My changelog\nA stable preamble\n\n- - -\n## 1.1.0");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_preserves_changelog_header() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(
+        "cog.toml",
+        "[changelog]\nheader = \"# My changelog\\nA stable preamble\"",
+    )?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).starts_with("# My changelog\nA stable preamble\n\n- - -\n## 1.1.1");
+    assert_that!(changelog.matches("# My changelog").count()).is_equal_to(1);
+    assert_that!(changelog).contains("## 1.1.0");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_preserves_crlf_changelog_line_endings() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(
+        "CHANGELOG.md",
+        "# Changelog\r\n\r\n- - -\r\n## 1.0.0 - 2022-01-01\r\n- - -\r\n\r\nfooter\r\n",
+    )?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).starts_with("# Changelog\r\n\r\n- - -\r\n## 1.1.0");
+    assert_that!(changelog).contains("## 1.0.0 - 2022-01-01\r\n");
+    assert_that!(changelog.matches('\n').count()).is_equal_to(changelog.matches("\r\n").count());
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_lf_changelog_line_ending() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write("cog.toml", "[changelog]\nline_ending = \"lf\"")?;
+    std::fs::write(
+        "CHANGELOG.md",
+        "# Changelog\r\n\r\n- - -\r\n## 1.0.0 - 2022-01-01\r\n- - -\r\n",
+    )?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).starts_with("# Changelog\n\n- - -\n## 1.1.0");
+    assert_that!(changelog).does_not_contain("\r");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_preserves_changelog_footer() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(
+        "cog.toml",
+        "[changelog]\nfooter = \"[1.1.0]: https://example.org/1.1.0\"",
+    )?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).ends_with("- - -\n[1.1.0]: https://example.org/1.1.0\n");
+    assert_that!(changelog
+        .matches("[1.1.0]: https://example.org/1.1.0")
+        .count())
+    .is_equal_to(1);
+    assert_that!(changelog).does_not_contain("Changelog generated by");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_latest_only_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write("cog.toml", "[changelog]\nlatest_only = true")?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).starts_with("# Changelog");
+    assert_that!(changelog).contains("## 1.1.1");
+    assert_that!(changelog).contains("- bug fix");
+    assert_that!(changelog).does_not_contain("## 1.1.0");
+    assert_that!(changelog).does_not_contain("- feature");
+    assert_that!(changelog).ends_with(
+        "- - -\n\nChangelog generated by [cocogitto](https://github.com/cocogitto/cocogitto).",
+    );
+    assert_that!(changelog.matches("- - -").count()).is_equal_to(2);
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_no_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--no-changelog")
+        .assert()
+        .success();
+
+    // Assert
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_tag_exists("1.1.0")?;
+    assert_that!(git_log_head()?.trim()).is_equal_to("feat: feature");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_changelog_path() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--changelog-path")
+        .arg("docs/release/CHANGELOG.md")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("docs/release/CHANGELOG.md")?;
+    assert_that!(changelog).contains("## 1.1.0");
+    assert_that!(changelog).contains("feature");
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_tag_exists("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_retry_tags_interrupted_bump() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    // Simulate a bump interrupted before tagging
+    run_cmd!(git tag -d 1.1.0;)?;
+    let head = run_fun!(git rev-parse HEAD)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--retry")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.1.0")?;
+    assert_that!(run_fun!(git rev-parse HEAD)?).is_equal_to(head.clone());
+    assert_that!(run_fun!(git rev-parse "1.1.0^{commit}")?).is_equal_to(head);
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_retry_fails_without_interrupted_bump() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--retry")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("version 1.1.0 is already tagged"));
+
+    git_commit("fix: bug fix")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--retry")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("HEAD is not a version commit"));
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_from_start_with_initial_version() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[bump]\ninitial_version = \"1.0.0\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat(taef): feature")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    assert_that!(Path::new("CHANGELOG.md")).exists();
+    assert_tag_exists("1.0.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_from_start_with_initial_version_keeps_manual_version() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[bump]\ninitial_version = \"1.0.0\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat(taef): feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--version")
+        .arg("0.5.0")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("0.5.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_amend() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--amend")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.1.0")?;
+    assert_that!(git_log_head()?.trim()).is_equal_to("feat: feature");
+    let head = run_fun!(git rev-parse HEAD)?;
+    let tagged = run_fun!(git rev-parse "1.1.0^{commit}")?;
+    assert_that!(tagged).is_equal_to(head);
+    let head_files = run_fun!(git show --name-only --format= HEAD)?;
+    assert_that!(head_files).contains("CHANGELOG.md");
+    assert_that!(run_fun!(git rev-list --count HEAD)?).is_equal_to("2".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_amend_fails_on_pushed_commit() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    run_cmd!(git update-ref refs/remotes/origin/master HEAD;)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--amend")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "already been pushed to origin/master",
+        ));
+
+    assert_tag_does_not_exist("1.1.0")?;
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_creates_annotated_tag_with_changelog_message() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let object_type = run_fun!(git cat-file -t 1.1.0)?;
+    assert_that!(object_type.as_str()).is_equal_to("tag");
+    let message = run_fun!(git tag -l "--format=%(contents)" 1.1.0)?;
+    assert_that!(message).starts_with("## 1.1.0");
+    assert_that!(message).contains("- feature");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_tag_message_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(
+        "cog.toml",
+        "[bump]\ntag_message_template = \"Release {{version}} ({{date}})\\n\\n{{changelog}}\"",
+    )?;
+    run_cmd!(git add .;)?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let today = chrono::Utc::today().naive_utc().to_string();
+    let message = run_fun!(git tag -l "--format=%(contents)" 1.1.0)?;
+    assert_that!(message).starts_with(&*format!("Release 1.1.0 ({})\n\n## 1.1.0", today));
+    assert_that!(message).contains("- feature");
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_patch_to_minor_threshold() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[bump]\npatch_to_minor_threshold = 3", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: fix 1")?;
+    git_commit("fix: fix 2")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    assert_tag_exists("1.0.1")?;
+    git_commit("fix: fix 3")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn consecutive_bumps_exclude_version_commit_from_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.1")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    let latest_release = changelog.split("- - -").nth(1).unwrap();
+    assert_that!(latest_release).contains("## 0.1.1");
+    assert_that!(latest_release).contains("- bug fix");
+    assert_that!(changelog.as_str()).does_not_contain("(version)");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_commit_message_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[bump]
+        commit_message_template = "chore(release): {{version}} [skip ci]"
+        "#
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_that!(git_log_head()?.trim()).is_equal_to("chore(release): 0.2.0 [skip ci]");
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog.as_str()).does_not_contain("chore(release)");
+    assert_that!(changelog.as_str()).does_not_contain("[skip ci]");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_skip_ci() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    assert_that!(git_log_head()?.trim()).is_equal_to("chore(version): 1.1.0 [skip ci]");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_message() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--message")
+        .arg("chore: release {{version}}")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    assert_that!(git_log_head()?.trim()).is_equal_to("chore: release 1.1.0");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_invalid_message_fails_with_verify_bump_commit() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[bump]\nverify_bump_commit = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--message")
+        .arg("release {{version}}")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid version commit message"));
+
+    assert_tag_does_not_exist("1.1.0")?;
+    assert_that!(git_log_head()?.trim()).is_equal_to("feat: feature");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_custom_skip_ci_marker() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[bump]
+        skip_ci = "[ci skip]"
+        "#
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        .assert()
+        .success();
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        // Assert
+        .assert()
+        .success();
+
+    assert_that!(git_log_head()?.trim()).is_equal_to("chore(version): 0.2.0 [ci skip]");
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog.as_str()).does_not_contain("[ci skip]");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_fails_on_dirty_working_tree() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    std::fs::write("unrelated", "content")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Cannot bump with a dirty working tree",
+        ))
+        .stderr(predicates::str::contains("new: unrelated"));
+
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_allow_dirty() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    std::fs::write("unrelated", "content")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--allow-dirty")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    let files = run_fun!(git show --name-only --format= HEAD)?;
+    assert_that!(files.lines().collect::<Vec<_>>()).contains("unrelated");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_ignores_changes_to_the_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    std::fs::write("CHANGELOG.md", "# Changelog\n- - -\n")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_without_release_commit_types() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]" > cog.toml;
+        echo "release_commit_types = [\"feat\", \"fix\", \"perf\"]" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("docs: update the readme")?;
+    git_commit("chore: update dependencies")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("No releasable changes"))
+        .stderr(predicates::str::contains("cog bump --auto --force"));
+
+    assert_tag_does_not_exist("1.0.1")?;
+    assert_that!(git_log_head()?.trim()).is_equal_to("chore: update dependencies");
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_without_release_commit_types_forced() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]" > cog.toml;
+        echo "release_commit_types = [\"feat\", \"fix\", \"perf\"]" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("docs: update the readme")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--force")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_with_non_semver_release_commit_type() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]" > cog.toml;
+        echo "release_commit_types = [\"feat\", \"fix\", \"perf\"]" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("perf: faster startup")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_collapses_unreleased_section_into_the_new_version() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: first feature")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: second feature")?;
+    let changelog = Command::cargo_bin("cog")?.arg("changelog").output()?.stdout;
+    let changelog = format!(
+        "# Changelog\n\n- - -\n{}- - -\n\nFooter\n",
+        String::from_utf8(changelog)?
+    );
+    git_add(&changelog, "CHANGELOG.md")?;
+    git_commit("chore: add changelog")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).does_not_contain("Unreleased");
+    let new_version = changelog.find("## 1.1.0").expect("new version section");
+    let previous_version = changelog
+        .find("## 1.0.0")
+        .expect("previous version section");
+    assert_that!(new_version).is_less_than(previous_version);
+    assert_that!(changelog.matches("second feature").count()).is_equal_to(1);
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_from_ref_overrides_the_range_start() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feature = git_commit("feat: feature")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--from-ref")
+        .arg(&feature)
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.0.1")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).contains("bug fix");
+    assert_that!(changelog).does_not_contain("- feature");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_from_unknown_ref_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--from-ref")
+        .arg("unknown")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("reference `unknown` not found"));
+
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_without_commit_to_release_skips_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("docs: update the readme")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("nothing to release"));
+
+    assert_tag_does_not_exist("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_without_commit_to_release_fails_with_error_policy() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[bump]\nempty_release = \"error\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("docs: update the readme")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains(
+            "No conventional commit found to bump current version",
+        ));
+
+    assert_tag_does_not_exist("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_without_release_commit_types_fails_with_error_policy() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "[bump]\nempty_release = \"error\"\nrelease_commit_types = [\"feat\", \"fix\"]",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("chore: update dependencies")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains("No releasable changes"))
+        .stderr(predicates::str::contains("cog bump --auto --force"));
+
+    assert_tag_does_not_exist("1.0.1")?;
+    Ok(())
+}
@@ -3,6 +3,7 @@ use assert_cmd::Command;
 use chrono::Utc;
 use cmd_lib::run_cmd;
 use indoc::{formatdoc, indoc};
+use predicates::prelude::{predicate, PredicateBooleanExt};
 use pretty_assertions::assert_eq;
 use sealed_test::prelude::*;
 use std::fs;
@@ -37,8 +38,6 @@ fn get_changelog_range() -> Result<()> {
                 - fix openssl missing in CD - (1c0d2e9) - oknozor
                 #### Documentation
                 - tag, conventional commit and license badges to readme - (da6f63d) - oknozor
-                #### Miscellaneous Chores
-                - **(version)** 0.32.3 - (0939f4c) - *oknozor*
 
                 - - -
 
@@ -47,8 +46,6 @@ fn get_changelog_range() -> Result<()> {
                 - **(cd)** bump setup-rust-action to v1.3.3 - (5350b11) - *oknozor*
                 #### Documentation
                 - add corrections to README - (9a33516) - oknozor
-                #### Miscellaneous Chores
-                - **(version)** 0.32.2 - (ef4803b) - *oknozor*
 
                 - - -
 
@@ -61,7 +58,6 @@ fn get_changelog_range() -> Result<()> {
                 - move check edit to dedicated subcommand and fix rebase - (fc74207) - oknozor
                 - remove config commit on init existing repo - (1028d0b) - oknozor
                 #### Miscellaneous Chores
-                - **(version)** 0.32.1 - (5bcfd6f) - *oknozor*
                 - update Cargo.toml - (72bd1e4) - oknozor
                 #### Refactoring
                 - change config name to cog.toml - (d4aa61b) - oknozor
@@ -193,6 +189,161 @@ fn get_changelog_at_tag() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn get_changelog_at_tag_with_previous_tag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let commit_one = git_commit("feat: feature 1")?;
+    git_tag("1.1.0")?;
+    let commit_two = git_commit("fix: bug fix")?;
+    git_tag("1.1.1")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--at")
+        .arg("1.1.1")
+        .arg("--previous-tag")
+        .arg("1.0.0")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = &changelog.stdout;
+    let changelog = String::from_utf8_lossy(changelog.as_slice());
+    let today = Utc::today().naive_utc();
+
+    assert_eq!(
+        changelog.as_ref(),
+        formatdoc!(
+            "## 1.1.1 - {today}
+                    #### Bug Fixes
+                    - bug fix - ({commit_two}) - Tom
+                    #### Features
+                    - feature 1 - ({commit_one}) - Tom
+
+                    ",
+            today = today,
+            commit_one = &commit_one[0..7],
+            commit_two = &commit_two[0..7]
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_at_tag_with_previous_tag_not_an_ancestor_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.1.0")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--at")
+        .arg("1.0.0")
+        .arg("--previous-tag")
+        .arg("1.1.0")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "tag 1.1.0 is not an ancestor of 1.0.0",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_global_settings() -> Result<()> {
+    // Arrange
+    git_init()?;
+    fs::create_dir_all("xdg/cocogitto")?;
+    let global_settings = indoc!(
+        r#"tag_prefix = "v"
+
+        [changelog]
+        authors = [{ signature = "Tom", username = "tom-gh" }]
+        "#
+    );
+    fs::write("xdg/cocogitto/cog.toml", global_settings)?;
+    fs::write("cog.toml", "tag_prefix = \"release-\"\n")?;
+    run_cmd!(
+        echo xdg > .gitignore;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: feature 1")?;
+    git_tag("release-1.0.0")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .env("XDG_CONFIG_HOME", "xdg")
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.starts_with("## release-1.0.0 - "));
+    assert!(changelog.contains("- feature 1 - "));
+    assert!(changelog.contains(" - tom-gh"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_strict_fails_on_skipped_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    let bad_commit = git_commit("toto: not a known type")?;
+    git_commit("this is not a conventional commit")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--strict")
+        // Assert
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "2 commit(s) left out of the changelog",
+        ))
+        .stderr(predicate::str::contains(format!(
+            "{}: Commit type `toto` not allowed",
+            &bad_commit[0..7]
+        )));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_strict_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--strict")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("- feature 1 - "));
+
+    Ok(())
+}
+
 #[sealed_test]
 fn get_changelog_with_tag_prefix() -> Result<()> {
     // Arrange
@@ -245,6 +396,64 @@ fn get_changelog_with_tag_prefix() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn get_changelog_with_scoped_tag_format_only_lists_package_releases() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]\ntag_format = \"{scope}@{version}\"" > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat(api): api feature")?;
+    git_tag("api@1.0.0")?;
+    git_commit("feat(web): web feature")?;
+    git_tag("web@1.0.0")?;
+    git_commit("fix(api): api fix")?;
+    git_tag("api@1.0.1")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--package")
+        .arg("api")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.contains("## api@1.0.1 - "));
+    assert!(changelog.contains("## api@1.0.0 - "));
+    assert!(!changelog.contains("web@1.0.0"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_scoped_tag_format_requires_package() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]\ntag_format = \"{scope}@{version}\"" > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat(api): api feature")?;
+    git_tag("api@1.0.0")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "select the package with `--package`",
+        ));
+
+    Ok(())
+}
+
 #[sealed_test]
 fn get_changelog_at_tag_prefix() -> Result<()> {
     // Arrange
@@ -262,7 +471,7 @@ fn get_changelog_at_tag_prefix() -> Result<()> {
     git_tag("v1.0.0")?;
     let commit_two = git_commit("feat: feature 1")?;
     let commit_three = git_commit("fix: bug fix 1")?;
-    let commit_four = git_commit("chore(version): v2.0.0")?;
+    git_commit("chore(version): v2.0.0")?;
     git_tag("v2.0.0")?;
     let _ = git_commit("feat: end")?;
 
@@ -287,14 +496,11 @@ fn get_changelog_at_tag_prefix() -> Result<()> {
                     - bug fix 1 - ({commit_three}) - Tom
                     #### Features
                     - feature 1 - ({commit_two}) - Tom
-                    #### Miscellaneous Chores
-                    - **(version)** v2.0.0 - ({commit_four}) - Tom
 
                     ",
             today = today,
             commit_two = &commit_two[0..7],
-            commit_three = &commit_three[0..7],
-            commit_four = &commit_four[0..7]
+            commit_three = &commit_three[0..7]
         )
     );
     Ok(())
@@ -310,7 +516,7 @@ fn get_changelog_from_tag_to_tagged_head() -> Result<()> {
     git_tag("1.0.0")?;
     let commit_three = git_commit("feat: feature 2")?;
     let commit_four = git_commit("fix: bug fix 1")?;
-    let commit_five = git_commit("chore(version): 2.0.0")?;
+    git_commit("chore(version): 2.0.0")?;
     git_tag("2.0.0")?;
 
     // Act
@@ -332,8 +538,6 @@ fn get_changelog_from_tag_to_tagged_head() -> Result<()> {
                 - bug fix 1 - ({commit_four}) - Tom
                 #### Features
                 - feature 2 - ({commit_three}) - Tom
-                #### Miscellaneous Chores
-                - **(version)** 2.0.0 - ({commit_five}) - Tom
 
                 - - -
 
@@ -348,7 +552,6 @@ fn get_changelog_from_tag_to_tagged_head() -> Result<()> {
             commit_two = &commit_two[0..7],
             commit_three = &commit_three[0..7],
             commit_four = &commit_four[0..7],
-            commit_five = &commit_five[0..7],
         )
     );
     Ok(())
@@ -380,7 +583,7 @@ fn get_changelog_whith_custom_template() -> Result<()> {
     git_tag("1.0.0")?;
     let commit_three = git_commit("feat: feature 2")?;
     let commit_four = git_commit("fix: bug fix 1")?;
-    let commit_five = git_commit("chore(version): 2.0.0")?;
+    git_commit("chore(version): 2.0.0")?;
     git_tag("2.0.0")?;
 
     // Act
@@ -404,8 +607,6 @@ fn get_changelog_whith_custom_template() -> Result<()> {
             -  bug fix 1 - ([{commit_four_short}](https://github.com/test/test/commit/{commit_four})) - Tom
             #### Features
             -  feature 2 - ([{commit_three_short}](https://github.com/test/test/commit/{commit_three})) - Tom
-            #### Miscellaneous Chores
-            - **(version)** 2.0.0 - ([{commit_five_short}](https://github.com/test/test/commit/{commit_five})) - Tom
 
             - - -
 
@@ -425,9 +626,1695 @@ fn get_changelog_whith_custom_template() -> Result<()> {
             commit_three_short = &commit_three[0..7],
             commit_four = &commit_four,
             commit_four_short = &commit_four[0..7],
-            commit_five = &commit_five,
-            commit_five_short = &commit_five[0..7],
         )
     );
     Ok(())
 }
+
+#[sealed_test]
+fn get_changelog_with_contributors() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        contributors = true
+        authors = [
+            { signature = \"Jane Doe\", username = \"jdoe\" }
+        ]"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    git_commit("fix: bug fix")?;
+    run_cmd!(git commit --allow-empty -q --author "Jane Doe <jane@example.org>" -m "fix: another fix";)?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = &changelog.stdout;
+    let changelog = String::from_utf8_lossy(changelog.as_slice());
+
+    assert!(changelog.contains(indoc!(
+        "#### Contributors
+        - Tom (2 commits)
+        - @jdoe (1 commit)
+        "
+    )));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_coauthors_as_contributors() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        contributors = true
+        count_coauthors = true
+        authors = [
+            { signature = \"Jane Doe\", username = \"jdoe\" }
+        ]"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature\n\nCo-authored-by: Jane Doe <jane@example.org>")?;
+    git_commit("fix: bug fix\n\nCo-authored-by: Tom <toml.bombadil@example.org>")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = &changelog.stdout;
+    let changelog = String::from_utf8_lossy(changelog.as_slice());
+
+    assert!(changelog.contains(indoc!(
+        "#### Contributors
+        - Tom (2 commits)
+        - @jdoe (1 commit)
+        "
+    )));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_author_emails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[changelog]\nauthor_display = \"name_email\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    run_cmd!(git commit --allow-empty -q --author "Jane Doe <jane@example.org>" -m "fix: bug fix";)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            ") - Jane Doe <jane@example.org>\n",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_keepachangelog_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[changelog.keepachangelog_categories]
+        docs = "Changed"
+        "#
+    );
+    git_add(settings, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feat = git_commit("feat(api): add an endpoint")?;
+    let fix = git_commit("fix: bug fix")?;
+    let docs = git_commit("docs: document the endpoint")?;
+    git_commit("ci: run the tests on push")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--template")
+        .arg("keepachangelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert_eq!(
+        changelog,
+        formatdoc!(
+            "## [Unreleased]
+            ### Added
+            - **(api)** add an endpoint - ({feat})
+
+            ### Changed
+            - document the endpoint - ({docs})
+
+            ### Fixed
+            - bug fix - ({fix})
+
+
+            ",
+            feat = &feat[0..7],
+            docs = &docs[0..7],
+            fix = &fix[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_keepachangelog_format() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feat = git_commit("feat: add an endpoint")?;
+    let fix = git_commit("fix: bug fix")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--format")
+        .arg("keepachangelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert_eq!(
+        changelog,
+        formatdoc!(
+            "## [Unreleased]
+            ### Added
+            - add an endpoint - ({feat})
+
+            ### Fixed
+            - bug fix - ({fix})
+
+
+            ",
+            feat = &feat[0..7],
+            fix = &fix[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_format_conflicts_with_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--format")
+        .arg("keepachangelog")
+        .arg("--template")
+        .arg("remote")
+        // Assert
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[sealed_test]
+fn get_flat_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feat = git_commit("feat(api): add an endpoint")?;
+    let fix = git_commit("fix: bug fix")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--flat")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(!changelog.contains("####"));
+    assert!(changelog.contains(&formatdoc!(
+        "- bug fix - ({fix}) - Tom
+        - **(api)** add an endpoint - ({feat}) - Tom
+        ",
+        fix = &fix[0..7],
+        feat = &feat[0..7],
+    )));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_flat_changelog_from_settings() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[changelog]\nflat = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add an endpoint")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("- bug fix"))
+        .stdout(predicate::str::contains("####").not());
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_no_changes_placeholder() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        no_changes_placeholder = \"No notable changes\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("wip: not a changelog type")?;
+    git_tag("1.1.0")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--at")
+        .arg("1.1.0")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let today = Utc::today().naive_utc().to_string();
+
+    assert_eq!(
+        changelog.as_ref(),
+        formatdoc!(
+            "## 1.1.0 - {today}
+            No notable changes
+
+            ",
+            today = today
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_empty_sections() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        show_empty_sections = true"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("feat: a feature")?;
+    git_tag("1.1.0")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--at")
+        .arg("1.1.0")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains("#### Documentation\n#### Features\n- a feature"));
+    let bug_fixes = changelog.find("#### Bug Fixes\n").unwrap();
+    let features = changelog.find("#### Features").unwrap();
+    let chores = changelog.find("#### Miscellaneous Chores\n").unwrap();
+    assert!(bug_fixes < features && features < chores);
+    assert_eq!(changelog.matches("#### Features").count(), 1);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_omits_empty_sections_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("wip: not a changelog type")?;
+    git_tag("1.1.0")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--at")
+        .arg("1.1.0")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(!changelog.contains("####"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_unknown_type_section() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        unknown_type_policy = \"section:Other\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: a feature")?;
+    git_commit("wip: an unknown type")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains("#### Features\n- a feature"));
+    assert!(changelog.contains("#### Other\n- an unknown type"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_unknown_type_skipped() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        unknown_type_policy = \"skip\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: a feature")?;
+    git_commit("wip: an unknown type")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains("#### Features\n- a feature"));
+    assert!(!changelog.contains("an unknown type"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_unknown_type_error() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        unknown_type_policy = \"error\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: a feature")?;
+    git_commit("wip: an unknown type")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown commit type"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_from_to_split_by_tag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let commit_three = git_commit("feat: feature 1")?;
+    git_tag("1.1.0")?;
+    let commit_two = git_commit("fix: bug fix 1")?;
+    git_tag("1.1.1")?;
+    let commit_one = git_commit("feat: feature 2")?;
+    git_tag("1.2.0")?;
+    git_commit("feat: unreleased feature")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--from")
+        .arg("1.0.0")
+        .arg("--to")
+        .arg("1.2.0")
+        .arg("--split-by-tag")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let today = Utc::today().naive_utc().to_string();
+
+    assert_eq!(
+        changelog.as_ref(),
+        formatdoc!(
+            "## 1.2.0 - {today}
+            #### Features
+            - feature 2 - ({commit_one}) - Tom
+
+            - - -
+
+            ## 1.1.1 - {today}
+            #### Bug Fixes
+            - bug fix 1 - ({commit_two}) - Tom
+
+            - - -
+
+            ## 1.1.0 - {today}
+            #### Features
+            - feature 1 - ({commit_three}) - Tom
+
+            ",
+            today = today,
+            commit_one = &commit_one[0..7],
+            commit_two = &commit_two[0..7],
+            commit_three = &commit_three[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_from_to_without_split_by_tag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.1.0")?;
+    git_commit("feat: feature 2")?;
+    git_tag("1.2.0")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--from")
+        .arg("1.0.0")
+        .arg("--to")
+        .arg("1.2.0")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert_eq!(changelog.matches("## 1.").count(), 1);
+    assert!(changelog.contains("- feature 2"));
+    assert!(changelog.contains("- feature 1"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_split_by_tag_requires_range() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--split-by-tag")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--from"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_from_latest_release() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.1.0")?;
+    git_commit("feat: unreleased feature")?;
+    git_commit("fix: unreleased fix")?;
+
+    let changelog = |args: &[&str]| -> Result<String> {
+        let output = Command::cargo_bin("cog")?
+            .arg("changelog")
+            .args(args)
+            .assert()
+            .success();
+        Ok(String::from_utf8_lossy(&output.get_output().stdout).to_string())
+    };
+
+    // Act
+    let from_latest_release = changelog(&["--from-latest-release"])?;
+    let to_head = changelog(&["--to", "HEAD"])?;
+    let from_latest_tag = changelog(&["--from", "1.1.0"])?;
+
+    // Assert
+    assert_eq!(from_latest_release, from_latest_tag);
+    assert_eq!(to_head, from_latest_tag);
+    assert!(from_latest_release.contains("- unreleased feature"));
+    assert!(from_latest_release.contains("- unreleased fix"));
+    assert!(!from_latest_release.contains("- feature 1"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_unreleased_only() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.1.0")?;
+    git_commit("feat: unreleased feature")?;
+    git_commit("fix: unreleased fix")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--unreleased-only")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.starts_with("## Unreleased"));
+    assert!(changelog.contains("- unreleased feature"));
+    assert!(changelog.contains("- unreleased fix"));
+    assert!(!changelog.contains("- feature 1"));
+    assert!(!changelog.contains("## 1."));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_unreleased_only_without_unreleased_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--unreleased-only")
+        // Assert
+        .assert()
+        .success()
+        .stdout("");
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_linked_issue() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        link_issues = true
+        issue_url_template = \"https://tracker.example.org/browse/{{id}}\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("fix: fix crash on startup #42")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog
+        .contains("- fix crash on startup [#42](https://tracker.example.org/browse/42) - ("));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_multiple_linked_issues_from_remote() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        link_issues = true
+        remote = \"github.com\"
+        owner = \"cocogitto\"
+        repository = \"cocogitto\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: support #1 and GH-2")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains(
+        "- support [#1](https://github.com/cocogitto/cocogitto/issues/1) \
+        and [GH-2](https://github.com/cocogitto/cocogitto/issues/2) - ("
+    ));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_merged_sections() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        section_merge = { perf = \"Features\", refactor = \"Internal\" }"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    let commit_one = git_commit("feat: a feature")?;
+    let commit_two = git_commit("perf: a faster parser")?;
+    let commit_three = git_commit("refactor: a cleanup")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert_eq!(
+        changelog.as_ref(),
+        formatdoc!(
+            "## Unreleased ({commit_one}..{commit_three})
+            #### Features
+            - a faster parser - ({commit_two}) - Tom
+            - a feature - ({commit_one}) - Tom
+            #### Internal
+            - a cleanup - ({commit_three}) - Tom
+
+            ",
+            commit_one = &commit_one[0..7],
+            commit_two = &commit_two[0..7],
+            commit_three = &commit_three[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_renamed_titles() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        titles = { feat = \"New Features\", fix = \"Fixes\" }"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    let commit_one = git_commit("feat: a feature")?;
+    let commit_two = git_commit("fix: a fix")?;
+    let commit_three = git_commit("docs: a doc")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert_eq!(
+        changelog.as_ref(),
+        formatdoc!(
+            "## Unreleased ({commit_one}..{commit_three})
+            #### Documentation
+            - a doc - ({commit_three}) - Tom
+            #### Fixes
+            - a fix - ({commit_two}) - Tom
+            #### New Features
+            - a feature - ({commit_one}) - Tom
+
+            ",
+            commit_one = &commit_one[0..7],
+            commit_two = &commit_two[0..7],
+            commit_three = &commit_three[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_full_hash() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        full_hash = true"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    let commit = git_commit("feat: a feature")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains(&format!("- a feature - ({}) - Tom", commit)));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_to_changelog_path() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: start")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--changelog-path")
+        .arg("docs/CHANGELOG.md")
+        .assert()
+        .success()
+        .stdout("");
+
+    // Assert
+    let changelog = fs::read_to_string("docs/CHANGELOG.md")?;
+    assert!(changelog.starts_with("# Changelog\n"));
+    assert!(changelog.contains("- - -\n## Unreleased"));
+    assert!(changelog.contains("#### Features"));
+    assert!(changelog.contains("start"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_to_existing_changelog_path_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: start")?;
+    fs::write("CHANGELOG.md", "# Changelog\n- - -\n## 1.0.0\n- - -\n")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--changelog-path")
+        .arg("CHANGELOG.md")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("use `--overwrite` to replace it"));
+
+    assert_eq!(
+        fs::read_to_string("CHANGELOG.md")?,
+        "# Changelog\n- - -\n## 1.0.0\n- - -\n"
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_overwrite_changelog_path_then_bump() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: start")?;
+    fs::write("CHANGELOG.md", "stale content")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--changelog-path")
+        .arg("CHANGELOG.md")
+        .arg("--overwrite")
+        .assert()
+        .success();
+    run_cmd!(git add CHANGELOG.md;)?;
+    git_commit("docs: regenerate the changelog")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = fs::read_to_string("CHANGELOG.md")?;
+    assert!(!changelog.contains("stale content"));
+    assert!(changelog.contains("## 1.1.0"));
+    assert!(!changelog.contains("## Unreleased"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_to_changelog_path_and_stdout_with_tee() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: start")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--changelog-path")
+        .arg("docs/CHANGELOG.md")
+        .arg("--tee")
+        .assert()
+        .success();
+
+    // Assert
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout);
+    let changelog = fs::read_to_string("docs/CHANGELOG.md")?;
+    assert!(changelog.contains("start"));
+    assert_eq!(stdout.as_ref(), changelog);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_tee_requires_changelog_path() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--tee")
+        // Assert
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_legacy_separator() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        legacy_separator = \" - \""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    let commit_one = git_commit("feat - a legacy feature")?;
+    let commit_two = git_commit("fix(parser): a fix")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert_eq!(
+        changelog.as_ref(),
+        formatdoc!(
+            "## Unreleased ({commit_one}..{commit_two})
+            #### Bug Fixes
+            - **(parser)** a fix - ({commit_two}) - Tom
+            #### Features
+            - a legacy feature - ({commit_one}) - Tom
+
+            ",
+            commit_one = &commit_one[0..7],
+            commit_two = &commit_two[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_diff_between_versions() -> Result<()> {
+    // Arrange
+    fs::write(
+        "CHANGELOG.md",
+        indoc!(
+            "# Changelog
+
+            - - -
+            ## 1.2.0 - 2022-03-01
+            #### Features
+            - feature two - (bbbbbbb) - Tom
+            - - -
+
+            ## 1.1.0 - 2022-02-01
+            #### Bug Fixes
+            - a fix - (aaaaaaa) - Tom
+            - - -
+
+            ## 1.0.0 - 2022-01-01
+            #### Features
+            - feature one - (0000000) - Tom
+            - - -
+            "
+        ),
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--diff")
+        .arg("1.1.0")
+        .arg("1.2.0")
+        // Assert
+        .assert()
+        .success()
+        .stdout("## 1.2.0 - 2022-03-01\n#### Features\n- feature two - (bbbbbbb) - Tom\n");
+
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--diff")
+        .arg("0.1.0")
+        .arg("1.2.0")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "version 0.1.0 not found in changelog",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_diff_from_changelog_path() -> Result<()> {
+    // Arrange
+    fs::create_dir("docs")?;
+    fs::write(
+        "docs/CHANGELOG.md",
+        indoc!(
+            "# Changelog
+
+            - - -
+            ## 1.1.0 - 2022-02-01
+            #### Bug Fixes
+            - a fix - (aaaaaaa) - Tom
+            - - -
+
+            ## 1.0.0 - 2022-01-01
+            #### Features
+            - feature one - (0000000) - Tom
+            - - -
+            "
+        ),
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--diff")
+        .arg("1.0.0")
+        .arg("1.1.0")
+        .arg("--changelog-path")
+        .arg("docs/CHANGELOG.md")
+        // Assert
+        .assert()
+        .success()
+        .stdout("## 1.1.0 - 2022-02-01\n#### Bug Fixes\n- a fix - (aaaaaaa) - Tom\n");
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_author_handles() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        template = \"remote\"
+        remote = \"github.com\"
+        owner = \"cocogitto\"
+        repository = \"cocogitto\"
+        author_handles = { \"Tom <toml.bombadil@themail.org>\" = \"ghuser\" }"
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: a feature")?;
+    run_cmd!(git -c user.name=Jerry commit --allow-empty -q -m "fix: a fix";)?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let feature = changelog.lines().find(|line| line.contains("a feature"));
+    let fix = changelog.lines().find(|line| line.contains("a fix"));
+
+    assert!(
+        matches!(feature, Some(line) if line.ends_with(" - [@ghuser](https://github.com/ghuser)"))
+    );
+    assert!(matches!(fix, Some(line) if line.ends_with(" - Jerry")));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_ticket_prefix() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        ticket_prefix_pattern = \"\\\\[[A-Z]+-\\\\d+\\\\]\""
+    );
+    fs::write("cog.toml", settings)?;
+    fs::write(
+        "template.md",
+        "{% for commit in commits %}{{ commit.ticket }}: {{ commit.summary }}\n{% endfor %}",
+    )?;
+    run_cmd!(
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("[PROJ-123] feat(api): add an endpoint")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("-t")
+        .arg("template.md")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("PROJ-123: add an endpoint"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_breaking_first() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[changelog]" > cog.toml;
+        echo "breaking_first = true" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat!: breaking feature")?;
+    git_commit("feat: regular feature")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let breaking = changelog.find("breaking feature").unwrap();
+    let regular = changelog.find("regular feature").unwrap();
+    assert!(breaking < regular);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_alpha_within_section_sort() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[changelog]" > cog.toml;
+        echo "within_section_sort = \"alpha\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: add bravo")?;
+    git_commit("feat: add charlie")?;
+    git_commit("feat: add alpha")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let alpha = changelog.find("add alpha").unwrap();
+    let bravo = changelog.find("add bravo").unwrap();
+    let charlie = changelog.find("add charlie").unwrap();
+    assert!(alpha < bravo);
+    assert!(bravo < charlie);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_keeps_history_order_within_sections_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    run_cmd!(
+        GIT_COMMITTER_DATE="2021-01-02T00:00:00" git commit --allow-empty -q -m "feat: add newer";
+        GIT_COMMITTER_DATE="2021-01-01T00:00:00" git commit --allow-empty -q -m "feat: add older on top";
+    )?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let on_top = changelog.find("add older on top").unwrap();
+    let newer = changelog.find("add newer").unwrap();
+    assert!(on_top < newer);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_date_within_section_sort() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[changelog]" > cog.toml;
+        echo "within_section_sort = \"date\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    run_cmd!(
+        GIT_COMMITTER_DATE="2021-01-02T00:00:00" git commit --allow-empty -q -m "feat: add newer";
+        GIT_COMMITTER_DATE="2021-01-01T00:00:00" git commit --allow-empty -q -m "feat: add older on top";
+    )?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let on_top = changelog.find("add older on top").unwrap();
+    let newer = changelog.find("add newer").unwrap();
+    assert!(newer < on_top);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_group_breaking_first() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat!: breaking feature")?;
+    git_commit("feat: regular feature")?;
+
+    // Act
+    let default_changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .assert()
+        .success();
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--group-breaking-first")
+        // Assert
+        .assert()
+        .success();
+
+    let default_changelog = default_changelog.get_output();
+    let default_changelog = String::from_utf8_lossy(&default_changelog.stdout);
+    assert!(default_changelog.find("regular feature") < default_changelog.find("breaking feature"));
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    let breaking = changelog.find("breaking feature").unwrap();
+    let regular = changelog.find("regular feature").unwrap();
+    assert!(breaking < regular);
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_thanks() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[changelog]" > cog.toml;
+        echo "thanks = true" >> cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature\n\nCo-authored-by: Jane Doe <jane@example.org>")?;
+    git_commit(
+        "fix: bug fix\n\nCo-authored-by: Jane Doe <jane@example.org>\nReviewed-by: John Smith <john@example.org>",
+    )?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert_eq!(changelog.matches("With thanks to").count(), 1);
+    assert!(changelog.contains("With thanks to Jane Doe, John Smith\n"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_changelog_footer() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: visible feature")?;
+    git_commit("fix: hidden fix\n\nChangelog: skip")?;
+    git_commit("feat: internal wording\n\nChangelog: Friendlier feature description")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.contains("- visible feature"));
+    assert!(!changelog.contains("hidden fix"));
+    assert!(!changelog.contains("#### Bug Fixes"));
+    assert!(changelog.contains("- Friendlier feature description"));
+    assert!(!changelog.contains("internal wording"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_anchors() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[changelog]" > cog.toml;
+        echo "anchors = true" >> cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("feat: feature")?;
+    git_tag("1.2.0")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.starts_with("<a id=\"unreleased\"></a>\n## Unreleased"));
+    assert!(changelog.contains("<a id=\"1-2-0\"></a>\n## 1.2.0 - "));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_merge_pull_request_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[changelog]" > cog.toml;
+        echo "parse_merge_pr = true" >> cog.toml;
+        echo "template = \"pr.tera\"" >> cog.toml;
+        echo "{% for commit in commits %}- {{ commit.summary }} (#{{ commit.pull_request }})\n{% endfor %}" > pr.tera;
+        git add .;
+        git commit -q -m "chore: init";
+        git checkout -q -b feature;
+    )?;
+    git_commit("work in progress")?;
+    run_cmd!(
+        git checkout -q -;
+        git merge -q --no-ff feature -m "Merge pull request #42 from org/feature" -m "feat(api): add the users endpoint";
+    )?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert_eq!(changelog.trim_end(), "- add the users endpoint (#42)");
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_trailing_pull_request() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "extract_trailing_pr = true" >> cog.toml;
+        echo "{% for commit in commits %}- {{ commit.summary }} (#{{ commit.pull_request }})\n{% endfor %}" > pr.tera;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add thing (#42)")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--template")
+        .arg("pr.tera")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert_eq!(changelog.trim_end(), "- add thing (#42)");
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_trailing_pull_request_default_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "extract_trailing_pr = true" >> cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    let sha = git_commit("feat: add thing (#42)")?;
+    let sha = &sha[0..7];
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.contains(&format!("- add thing (#42) - ({sha}) - Tom")));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_trailing_pull_request_remote_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "extract_trailing_pr = true" >> cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add thing (#42)")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--template")
+        .arg("remote")
+        .arg("--remote")
+        .arg("github.com")
+        .arg("--owner")
+        .arg("cocogitto")
+        .arg("--repository")
+        .arg("cocogitto")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.contains("add thing ([#42](https://github.com/cocogitto/cocogitto/pull/42))"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_path_filter() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    run_cmd!(mkdir -p packages/api packages/web;)?;
+    git_add("api", "packages/api/lib.rs")?;
+    git_commit("feat: api feature")?;
+    git_add("web", "packages/web/index.js")?;
+    git_commit("feat: web feature")?;
+    git_add("api v2", "packages/api/lib.rs")?;
+    git_add("web v2", "packages/web/index.js")?;
+    git_commit("fix: fix both packages")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        .arg("--path")
+        .arg("packages/api")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+    assert!(changelog.contains("- api feature"));
+    assert!(changelog.contains("- fix both packages"));
+    assert!(!changelog.contains("web feature"));
+    assert!(!changelog.contains("init"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_unreleased_on_top() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: first feature")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: second feature")?;
+    git_tag("1.1.0")?;
+    git_commit("fix: unreleased fix")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?.arg("changelog").output()?;
+
+    // Assert
+    let changelog = String::from_utf8(output.stdout)?;
+    let position = |title: &str| changelog.find(title).expect(title);
+    assert!(position("## Unreleased") < position("## 1.1.0"));
+    assert!(position("## 1.1.0") < position("## 1.0.0"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_without_unreleased() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[changelog]\nshow_unreleased = false", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: first feature")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: unreleased fix")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?.arg("changelog").output()?;
+
+    // Assert
+    let changelog = String::from_utf8(output.stdout)?;
+    assert!(changelog.starts_with("## 1.0.0"));
+    assert!(!changelog.contains("Unreleased"));
+    assert!(!changelog.contains("unreleased fix"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_without_excluded_authors() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "[changelog]\nexclude_authors = [\"dependabot[bot]\"]",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+    run_cmd!(git commit --allow-empty -q --author "dependabot[bot] <support@github.com>" -m "chore(deps): bump serde";)?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?.arg("changelog").output()?;
+    let log = Command::cargo_bin("cog")?
+        .env("PAGER", "cat")
+        .arg("log")
+        .arg("--format")
+        .arg("plain")
+        .output()?;
+
+    // Assert
+    let changelog = String::from_utf8(changelog.stdout)?;
+    let log = String::from_utf8(log.stdout)?;
+    assert!(changelog.contains("feature"));
+    assert!(!changelog.contains("bump serde"));
+    assert!(log.contains("bump serde"));
+    assert!(log.contains("Author: dependabot[bot]"));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_closes_note() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[changelog]
+        closes_note = true
+        link_issues = true
+        issue_url_template = \"https://tracker.example.org/browse/{{id}}\""
+    );
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -q -m "chore: init";
+    )?;
+    git_commit("fix: fix crash on startup\n\nCloses: #1, #2\nFixes #3")?;
+    git_commit("feat: unrelated feature")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains(
+        "- fix crash on startup (Closes [#1](https://tracker.example.org/browse/1), \
+        [#2](https://tracker.example.org/browse/2), \
+        [#3](https://tracker.example.org/browse/3)) - ("
+    ));
+    assert!(changelog.contains("- unrelated feature - ("));
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_with_commit_stats() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[changelog]\ncommit_stats = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_add("one\ntwo\nthree", "file")?;
+    git_commit("feat: add a file")?;
+    git_add("one", "file")?;
+    git_add("content", "other")?;
+    git_commit("fix: shrink the file")?;
+
+    // Act
+    let changelog = Command::cargo_bin("cog")?
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = String::from_utf8_lossy(&changelog.stdout);
+
+    assert!(changelog.contains("- add a file - ("));
+    assert!(changelog.contains(" - Tom - 1 files, +3 -0\n"));
+    assert!(changelog.contains(" - Tom - 2 files, +1 -2\n"));
+    Ok(())
+}
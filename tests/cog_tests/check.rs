@@ -2,8 +2,10 @@ use crate::helpers::*;
 
 use anyhow::Result;
 use assert_cmd::Command;
-use predicates::prelude::predicate;
+use cmd_lib::{run_cmd, run_fun};
+use predicates::prelude::{predicate, PredicateBooleanExt};
 use sealed_test::prelude::*;
+use std::fs;
 
 #[sealed_test]
 fn cog_check_ok() -> Result<()> {
@@ -83,3 +85,623 @@ fn cog_check_from_latest_tag_failure() -> Result<()> {
         .stderr(predicate::str::contains("Found 1 non compliant commits"));
     Ok(())
 }
+
+#[sealed_test]
+fn cog_check_legacy_separator_failure_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat - legacy feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Found 1 non compliant commits"));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_without_color() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("toto: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--color")
+        .arg("never")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Found 1 non compliant commits"))
+        .stderr(predicate::str::contains("\u{1b}[").not());
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_with_forced_color() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("toto: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("--color")
+        .arg("always")
+        .arg("check")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\u{1b}["));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_exits_with_0_when_all_commits_are_compliant() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(0);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_exits_with_1_on_non_compliant_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("toto: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(1);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_exits_with_2_on_invalid_config() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    std::fs::write("cog.toml", "not_a_setting = true")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(2);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_exits_with_2_outside_of_a_repository() -> Result<()> {
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(2);
+    Ok(())
+}
+
+fn git_commit_latin1(message: &[u8]) -> Result<()> {
+    std::fs::write("message.txt", message)?;
+    run_cmd!(
+        git -c i18n.commitEncoding=latin1 commit --allow-empty -q -F message.txt;
+        rm message.txt;
+    )?;
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_non_utf8_message_lossy_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit_latin1(b"feat: caf\xe9")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No errored commits"));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_non_utf8_message_skipped() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "on_invalid_utf8 = \"skip\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit_latin1(b"caf\xe9 is not conventional")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("with a non UTF-8 message"));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_non_utf8_message_error() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "on_invalid_utf8 = \"error\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit_latin1(b"feat: caf\xe9")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "Commit message is not valid UTF-8",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_cache_skips_checked_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: a feature")?;
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--cache")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("cached").not());
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--cache")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Skipped 2 cached commit(s)"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_cache_is_invalidated_on_settings_change() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: a feature")?;
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--cache")
+        .assert()
+        .success();
+    run_cmd!(echo "[commit]\nsubject_case = \"sentence\"" > cog.toml;)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--cache")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("cached").not());
+
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_cache_is_invalidated_on_global_settings_change() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: a feature")?;
+    Command::cargo_bin("cog")?
+        .env("XDG_CONFIG_HOME", "xdg")
+        .arg("check")
+        .arg("--cache")
+        .assert()
+        .success();
+    fs::create_dir_all("xdg/cocogitto")?;
+    fs::write(
+        "xdg/cocogitto/cog.toml",
+        "[commit]\nsubject_case = \"sentence\"",
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .env("XDG_CONFIG_HOME", "xdg")
+        .arg("check")
+        .arg("--cache")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("cached").not());
+
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_pr_base_from_origin_head() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("not a conventional commit")?;
+    run_cmd!(
+        git update-ref refs/remotes/origin/main HEAD;
+        git symbolic-ref refs/remotes/origin/HEAD refs/remotes/origin/main;
+        git checkout -q -b feature;
+    )?;
+    git_commit("feat: a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--pr-base")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No errored commits"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_pr_base_from_default_branch_setting() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write("cog.toml", "default_branch = \"trunk\"")?;
+    git_commit("chore: init")?;
+    git_commit("not a conventional commit")?;
+    run_cmd!(
+        git branch trunk;
+        git checkout -q -b feature;
+    )?;
+    git_commit("feat: a feature")?;
+    git_commit("still not conventional")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--pr-base")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("still not conventional"))
+        .stderr(predicate::str::contains("not a conventional commit").not());
+
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_pr_base_without_default_branch_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--pr-base")
+        // Assert
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("set `default_branch` in cog.toml"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_fix_whitespace_issues() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    run_cmd!(git commit -q --allow-empty --cleanup=verbatim -m "feat:  add  a feature  ";)?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--fix")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Fixed 1 commit(s)"));
+
+    let subjects = run_fun!(git log --format=%s)?;
+    assert_eq!(subjects, "fix: bug fix\nfeat: add a feature\nchore: init");
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_fix_dry_run_prints_proposed_messages() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    run_cmd!(git commit -q --allow-empty --cleanup=verbatim -m "feat:  add  a feature  ";)?;
+    git_commit("fix: bug fix")?;
+    let log_before = run_fun!(git log --format=%H%n%B)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--fix")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .stdout(predicate::str::contains("feat: add a feature\n"))
+        .stderr(predicate::str::contains("Would fix 1 commit(s)"));
+
+    let log_after = run_fun!(git log --format=%H%n%B)?;
+    assert_eq!(log_before, log_after);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_fix_reports_broken_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    run_cmd!(git commit -q --allow-empty --cleanup=verbatim -m "feat:add a feature ";)?;
+    git_commit("not a conventional commit")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--fix")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("not a conventional commit"));
+
+    let subjects = run_fun!(git log --format=%s)?;
+    assert_eq!(
+        subjects,
+        "not a conventional commit\nfeat: add a feature\nchore: init"
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_subject_case_violation() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "subject_case = \"lower\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: Capitalized feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "Commit description must start with a lowercase letter",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_long_body_line_violation() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "max_body_line_length = 20" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: a feature\n\nthis body line is way too long")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "Commit body line 1 is 30 characters long, the maximum is 20",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_breaking_change_without_description() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "require_breaking_body = true" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat!: drop the v1 api")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "Breaking change without a body or",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_duplicated_subject() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]" > cog.toml;
+        echo "flag_duplicates = true" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: init")?;
+    let first = git_commit("feat: add a feature")?;
+    git_commit("fix: fix a bug")?;
+    let second = git_commit("feat: add a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Found 1 non compliant commits"))
+        .stderr(predicate::str::contains(format!(
+            "Subject duplicated in commits {}, {}",
+            &second[0..7],
+            &first[0..7]
+        )));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_duplicated_subject_not_flagged_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: add a feature")?;
+    git_commit("feat: add a feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .success();
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_sarif_format_reports_violations() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let oid = git_commit("toto: feature")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("sarif")
+        .output()?;
+
+    // Assert
+    assert_eq!(output.status.code(), Some(1));
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "cocogitto");
+
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "commit-type-not-allowed");
+    assert_eq!(
+        results[0]["locations"][0]["logicalLocations"][0]["name"],
+        oid.as_str()
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_sarif_format_has_required_schema_fields() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let oid = git_commit("toto: feature")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("sarif")
+        .output()?;
+
+    // Assert
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        sarif["$schema"],
+        "https://json.schemastore.org/sarif-2.1.0.json"
+    );
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let run = &sarif["runs"][0];
+    assert!(run["tool"]["driver"]["name"].is_string());
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(
+        rules,
+        &vec![serde_json::json!({ "id": "commit-type-not-allowed" })]
+    );
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "commit-type-not-allowed");
+    assert_eq!(result["level"], "error");
+    assert!(result["message"]["text"].is_string());
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], oid.as_str());
+    assert_eq!(location["region"]["startLine"], 1);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_sarif_format_without_violations() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("sarif")
+        .output()?;
+
+    // Assert
+    assert!(output.status.success());
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(sarif["runs"][0]["results"], serde_json::json!([]));
+    Ok(())
+}
@@ -5,7 +5,9 @@ use crate::helpers::*;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
+use cmd_lib::{run_cmd, run_fun};
 use indoc::{formatdoc, indoc};
+use predicates::prelude::predicate;
 use pretty_assertions::assert_eq;
 use sealed_test::prelude::*;
 
@@ -130,3 +132,365 @@ fn empty_commit_err() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn commit_with_signoff() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("--signoff")
+        // Assert
+        .assert()
+        .success();
+
+    let user = run_fun!(git config user.name)?;
+    let email = run_fun!(git config user.email)?;
+    assert_eq!(
+        git_log_head()?.trim_end(),
+        format!(
+            "feat: this is a commit message\n\nSigned-off-by: {} <{}>",
+            user, email
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_signoff_short_flag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("-s")
+        // Assert
+        .assert()
+        .success();
+
+    assert!(git_log_head()?.contains("\n\nSigned-off-by: Tom <toml.bombadil@themail.org>"));
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_signoff_without_git_identity_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        git config --local --unset user.name;
+        git config --local --unset user.email;
+    )?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .env("HOME", ".")
+        .env("XDG_CONFIG_HOME", ".")
+        .env("GIT_CONFIG_NOSYSTEM", "1")
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("--signoff")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cannot sign off without a git identity",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_help_displays_commit_type_example() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            "[commit_types]
+            hotfix = { changelog_title = \"Hotfixes\", help_message = \"Urgent production fix\", example = \"hotfix(api): restore the login endpoint\" }"
+        ),
+        "cog.toml",
+    )?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("--help")
+        .output()?;
+
+    // Assert
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("hotfix      Urgent production fix"));
+    assert!(stdout.contains("example: hotfix(api): restore the login endpoint"));
+    assert!(stdout.contains("feat        Features"));
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_help_displays_commit_type_argument_help() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            "[commit_types]
+            feat = { changelog_title = \"Features\", message_help = \"User facing change, imperative mood\", scope_help = \"One of api, cli\", body_help = \"Link the design doc\" }"
+        ),
+        "cog.toml",
+    )?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("--help")
+        .output()?;
+
+    // Assert
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("message: User facing change, imperative mood"));
+    assert!(stdout.contains("--scope: One of api, cli"));
+    assert!(stdout.contains("--body: Link the design doc"));
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_scope_body_and_footers() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("--body")
+        .arg("this is the body")
+        .arg("--scope")
+        .arg("api")
+        .arg("--footer")
+        .arg("Refs: #42")
+        .arg("--footer")
+        .arg("Reviewed-by: Jane Doe")
+        // Assert
+        .assert()
+        .success();
+
+    assert_eq!(
+        git_log_head()?.trim_end(),
+        indoc!(
+            "feat(api): this is a commit message
+
+            this is the body
+
+            Refs: #42
+            Reviewed-by: Jane Doe"
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_body_does_not_set_scope() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("fix")
+        .arg("this is a commit message")
+        .arg("--body")
+        .arg("body text")
+        // Assert
+        .assert()
+        .success();
+
+    assert_eq!(
+        git_log_head()?.trim_end(),
+        "fix: this is a commit message\n\nbody text"
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_deprecated_positional_scope() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("fix")
+        .arg("this is a commit message")
+        .arg("scope")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("use `--scope` instead"));
+
+    assert_eq!(
+        git_log_head()?.trim_end(),
+        "fix(scope): this is a commit message"
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_both_scopes_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("fix")
+        .arg("this is a commit message")
+        .arg("scope")
+        .arg("--scope")
+        .arg("other")
+        // Assert
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_configured_alias() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[commit_aliases]\nf = \"feat\"", "cog.toml")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("f")
+        .arg("add a feature")
+        .arg("--scope")
+        .arg("api")
+        // Assert
+        .assert()
+        .success();
+
+    assert_eq!(git_log_head()?.trim_end(), "feat(api): add a feature");
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_alias_colliding_with_subcommand_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[commit_aliases]\nlog = \"feat\"", "cog.toml")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "commit alias `log` collides with the `cog log` subcommand",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn unknown_subcommand_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("f")
+        .arg("add a feature")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Found argument 'f' which wasn't expected",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn misspelled_subcommand_suggests_the_builtin_one() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bumpp")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Did you mean 'bump'?"));
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_scope_fail_if_not_a_repository() -> Result<()> {
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("--scope")
+        .arg("scope")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with(
+            "Error: failed to open repository",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn unstaged_changes_commit_with_scope_err() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write("test_file", "content")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("--scope")
+        .arg("scope")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with("Error: Untracked files :"));
+    Ok(())
+}
+
+#[sealed_test]
+fn empty_commit_with_scope_err() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("--scope")
+        .arg("scope")
+        // Assert
+        .assert()
+        .failure()
+        .stderr("Error: nothing to commit (create/copy files and use \"git add\" to track)\n\n");
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::process::Command;
+
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use sealed_test::prelude::*;
+use speculoos::prelude::*;
+
+#[sealed_test]
+fn diff_check_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(".git/COMMIT_EDITMSG", "feat: add a feature\n")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("diff-check")
+        // Assert
+        .assert()
+        .success();
+    Ok(())
+}
+
+#[sealed_test]
+fn diff_check_rejects_correctable_message_without_fix() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(".git/COMMIT_EDITMSG", "feat:add a feature  \n")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("diff-check")
+        // Assert
+        .assert()
+        .failure();
+
+    let message = std::fs::read_to_string(".git/COMMIT_EDITMSG")?;
+    assert_that!(message).is_equal_to("feat:add a feature  \n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn diff_check_fixes_correctable_message() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write("message.txt", "feat:add a feature  \n\nthe body \n")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("diff-check")
+        .arg("message.txt")
+        .arg("--fix")
+        // Assert
+        .assert()
+        .success();
+
+    let message = std::fs::read_to_string("message.txt")?;
+    assert_that!(message).is_equal_to("feat: add a feature\n\nthe body\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn diff_check_rejects_non_correctable_message() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(".git/COMMIT_EDITMSG", "add a feature\n")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("diff-check")
+        .arg("--fix")
+        // Assert
+        .assert()
+        .failure();
+
+    let message = std::fs::read_to_string(".git/COMMIT_EDITMSG")?;
+    assert_that!(message).is_equal_to("add a feature\n".to_string());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::process::Command;
+
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use sealed_test::prelude::*;
+
+#[sealed_test]
+fn doctor_fails_outside_of_a_repository() -> Result<()> {
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("doctor")
+        // Assert
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains(
+            "git repository: not a git repository",
+        ))
+        .stdout(predicates::str::contains("hint: run `git init`"))
+        .stderr(predicates::str::contains("1 critical check(s) failed"));
+    Ok(())
+}
+
+#[sealed_test]
+fn doctor_warns_about_missing_config() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: first commit")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("doctor")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "config: cog.toml not found, using the default config",
+        ))
+        .stdout(predicates::str::contains(
+            "hint: run `cog init` to create one",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn doctor_fails_on_invalid_config() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("tag_prefix = [", "cog.toml")?;
+    git_commit("chore: first commit")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("doctor")
+        // Assert
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains(
+            "config: 1 problem(s) found in cog.toml",
+        ));
+    Ok(())
+}
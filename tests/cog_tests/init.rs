@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 
+use cocogitto::settings::Settings;
 use cocogitto::CONFIG_PATH;
 
 use crate::helpers::*;
@@ -70,3 +71,43 @@ fn init_current_dir_with_no_arg() -> Result<()> {
         .success();
     Ok(())
 }
+
+#[sealed_test]
+fn init_with_preset_template() -> Result<()> {
+    for (preset, expected_key) in [
+        ("minimal", "ignore_merge_commits = true"),
+        ("monorepo", "scope_required_types = ["),
+        ("gitmoji", "gitmoji = true"),
+    ] {
+        // Act
+        Command::cargo_bin("cog")?
+            .arg("init")
+            .arg(preset)
+            .arg("--template")
+            .arg(preset)
+            .assert()
+            .success();
+
+        // Assert
+        let config_path = Path::new(preset).join(CONFIG_PATH);
+        let config = std::fs::read_to_string(&config_path)?;
+        assert_that!(config.as_str()).contains(expected_key);
+        assert_that!(Settings::validate_file(&config_path)?).is_empty();
+    }
+    Ok(())
+}
+
+#[sealed_test]
+fn init_with_unknown_preset_template_fails() -> Result<()> {
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("init")
+        .arg("--template")
+        .arg("unknown")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_that!(Path::new(CONFIG_PATH)).does_not_exist();
+    Ok(())
+}
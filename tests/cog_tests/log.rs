@@ -0,0 +1,196 @@
+use std::process::Command;
+
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use cmd_lib::{run_cmd, run_fun};
+use sealed_test::prelude::*;
+use speculoos::prelude::*;
+
+fn cog_log(format: &str) -> Result<String> {
+    let output = Command::cargo_bin("cog")?
+        .env("PAGER", "cat")
+        .arg("--color")
+        .arg("always")
+        .arg("log")
+        .arg("--format")
+        .arg(format)
+        .output()?;
+
+    assert_that!(output.status.success()).is_true();
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[sealed_test]
+fn log_terminal_format_is_colored() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat(api): add an endpoint")?;
+
+    // Act
+    let log = cog_log("terminal")?;
+
+    // Assert
+    assert_that!(log).contains("\u{1b}[");
+    assert_that!(log).contains("add an endpoint");
+    Ok(())
+}
+
+#[sealed_test]
+fn log_plain_format_is_not_colored() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat(api): add an endpoint")?;
+    git_commit("not a conventional commit")?;
+
+    // Act
+    let log = cog_log("plain")?;
+
+    // Assert
+    assert_that!(log).does_not_contain("\u{1b}[");
+    assert_that!(log).contains("add an endpoint");
+    assert_that!(log).contains("\tAuthor: ");
+    assert_that!(log).contains("\tScope: api");
+    assert_that!(log).contains("Errored commit: ");
+    Ok(())
+}
+
+#[sealed_test]
+fn log_markdown_format_is_a_list() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let first = git_commit("feat(api)!: add an endpoint")?;
+    let second = git_commit("fix: fix a bug")?;
+
+    // Act
+    let log = cog_log("markdown")?;
+
+    // Assert
+    assert_that!(log).does_not_contain("\u{1b}[");
+    let lines: Vec<&str> = log.lines().collect();
+    assert_that!(lines).is_equal_to(vec![
+        format!("- **fix:** fix a bug - (`{}`) - Tom", &second[0..6]).as_str(),
+        format!(
+            "- **BREAKING CHANGE** **feat(api):** add an endpoint - (`{}`) - Tom",
+            &first[0..6]
+        )
+        .as_str(),
+    ]);
+    Ok(())
+}
+
+// Commit at a fixed date so the chronological order is deterministic
+fn git_commit_at(message: &str, timestamp: u32) -> Result<String> {
+    let date = format!("{} +0000", timestamp);
+    std::env::set_var("GIT_AUTHOR_DATE", &date);
+    std::env::set_var("GIT_COMMITTER_DATE", &date);
+    git_commit(message)
+}
+
+fn cog_log_summaries(args: &[&str]) -> Result<Vec<String>> {
+    let output = Command::cargo_bin("cog")?
+        .env("PAGER", "cat")
+        .arg("log")
+        .arg("--format")
+        .arg("markdown")
+        .args(args)
+        .output()?;
+
+    assert_that!(output.status.success()).is_true();
+    let summaries = String::from_utf8(output.stdout)?
+        .lines()
+        .filter_map(|line| line.split("** ").nth(1))
+        .filter_map(|line| line.split(" - ").next())
+        .map(str::to_string)
+        .collect();
+
+    Ok(summaries)
+}
+
+#[sealed_test]
+fn log_topo_order_does_not_intermix_branches() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit_at("chore: init", 1_000_000_000)?;
+    run_cmd!(git checkout -q -b feature;)?;
+    git_commit_at("feat: feature one", 1_000_001_000)?;
+    run_cmd!(git checkout -q -;)?;
+    git_commit_at("fix: main one", 1_000_002_000)?;
+    run_cmd!(git checkout -q -;)?;
+    git_commit_at("feat: feature two", 1_000_003_000)?;
+    run_cmd!(git checkout -q -;)?;
+    git_commit_at("fix: main two", 1_000_004_000)?;
+    run_cmd!(git merge -q --no-ff feature -m "Merge branch feature";)?;
+    let git_topo_order = run_fun!(git log --topo-order --no-merges --format=%s)?;
+
+    // Act
+    let chronological = cog_log_summaries(&[])?;
+    let topological = cog_log_summaries(&["--topo-order"])?;
+    let reversed = cog_log_summaries(&["--topo-order", "--reverse"])?;
+
+    // Assert
+    assert_that!(chronological).is_equal_to(vec![
+        "main two".to_string(),
+        "feature two".to_string(),
+        "main one".to_string(),
+        "feature one".to_string(),
+        "init".to_string(),
+    ]);
+    let expected: Vec<String> = git_topo_order
+        .lines()
+        .map(|summary| summary.split(": ").nth(1).unwrap().to_string())
+        .collect();
+    assert_that!(topological).is_equal_to(&expected);
+    assert_that!(topological).is_not_equal_to(&chronological);
+    let mut expected_reversed = expected;
+    expected_reversed.reverse();
+    assert_that!(reversed).is_equal_to(expected_reversed);
+    Ok(())
+}
+
+#[sealed_test]
+fn log_shows_scope_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat(api): add an endpoint")?;
+
+    // Act
+    let log = cog_log("plain")?;
+    let without_scope = Command::cargo_bin("cog")?
+        .env("PAGER", "cat")
+        .arg("log")
+        .arg("--format")
+        .arg("plain")
+        .arg("--no-scope")
+        .output()?;
+
+    // Assert
+    assert_that!(log).contains("\tType: feat");
+    assert_that!(log).contains("\tScope: api");
+    let without_scope = String::from_utf8(without_scope.stdout)?;
+    assert_that!(without_scope).contains("\tType: feat");
+    assert_that!(without_scope).does_not_contain("Scope:");
+    Ok(())
+}
+
+#[sealed_test]
+fn log_markdown_format_without_scope() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let commit = git_commit("feat(api): add an endpoint")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .env("PAGER", "cat")
+        .arg("log")
+        .arg("--format")
+        .arg("markdown")
+        .arg("--no-scope")
+        .output()?;
+
+    // Assert
+    assert_that!(String::from_utf8(output.stdout)?.trim_end())
+        .is_equal_to(format!("- **feat:** add an endpoint - (`{}`) - Tom", &commit[0..6]).as_str());
+    Ok(())
+}
@@ -1,6 +1,14 @@
+mod amend;
 mod bump;
 mod changelog;
 mod check;
 mod commit;
+mod diff_check;
+mod doctor;
 mod init;
+mod log;
+mod next_version;
+mod reword;
+mod tags;
+mod validate_config;
 mod verify;
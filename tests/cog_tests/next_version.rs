@@ -0,0 +1,105 @@
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use cmd_lib::{run_cmd, run_fun};
+use predicates::prelude::PredicateBooleanExt;
+use sealed_test::prelude::*;
+use speculoos::prelude::*;
+use std::process::Command;
+
+#[sealed_test]
+fn next_version_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("tag_prefix = \"v\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("v1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("next-version")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0\n");
+
+    assert_tag_does_not_exist("v1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_with_shields_format() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("tag_prefix = \"v\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("v1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("next-version")
+        .arg("--format")
+        .arg("shields")
+        .assert()
+        .success();
+
+    // Assert
+    let output = String::from_utf8_lossy(&output.get_output().stdout).to_string();
+    let badge: serde_json::Value = serde_json::from_str(&output)?;
+    assert_that!(badge).is_equal_to(serde_json::json!({
+        "schemaVersion": 1,
+        "label": "next",
+        "message": "1.1.0",
+        "color": "blue",
+    }));
+    assert_tag_does_not_exist("v1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_with_pre_release() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("next-version")
+        .arg("--pre")
+        .arg("alpha.1")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.0.1-alpha.1\n");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_on_dirty_tree() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    std::fs::write("file", "content")?;
+    run_cmd!(git add file;)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("next-version")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0\n")
+        .stderr(predicates::str::contains("default configuration").not());
+
+    let staged = run_fun!(git diff --cached --name-only)?;
+    assert_that!(staged.as_str()).is_equal_to("file");
+    Ok(())
+}
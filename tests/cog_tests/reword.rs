@@ -0,0 +1,114 @@
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use cmd_lib::{run_cmd, run_fun};
+use sealed_test::prelude::*;
+use speculoos::prelude::*;
+use std::process::Command;
+
+#[sealed_test]
+fn reword_mid_history_commit() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let commit = git_commit("a non compliant commit")?;
+    git_commit("feat: a feature")?;
+    git_commit("fix: a fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("reword")
+        .arg(&commit[0..7])
+        .arg("--message")
+        .arg("docs: a compliant commit")
+        .assert()
+        .success();
+
+    // Assert
+    let log = run_fun!(git log --format=%s)?;
+    assert_that!(log).is_equal_to(
+        "fix: a fix\nfeat: a feature\ndocs: a compliant commit\nchore: init".to_string(),
+    );
+    Command::cargo_bin("cog")?.arg("check").assert().success();
+    Ok(())
+}
+
+#[sealed_test]
+fn reword_head_commit() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("a non compliant commit")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("reword")
+        .arg("HEAD")
+        .arg("-m")
+        .arg("feat: a feature")
+        .assert()
+        .success();
+
+    // Assert
+    assert_that!(git_log_head()?.trim()).is_equal_to("feat: a feature");
+    assert_that!(run_fun!(git rev-list --count HEAD)?).is_equal_to("2".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn reword_with_non_compliant_message_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let head = git_commit("a non compliant commit")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("reword")
+        .arg("HEAD")
+        .arg("--message")
+        .arg("still not compliant")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_that!(run_fun!(git rev-parse HEAD)?).is_equal_to(head);
+    Ok(())
+}
+
+#[sealed_test]
+fn reword_pushed_commit_requires_force() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let commit = git_commit("a non compliant commit")?;
+    git_commit("feat: a feature")?;
+    run_cmd!(git update-ref refs/remotes/origin/master HEAD;)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("reword")
+        .arg(&commit)
+        .arg("--message")
+        .arg("docs: a compliant commit")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "already been pushed to origin/master",
+        ));
+
+    Command::cargo_bin("cog")?
+        .arg("reword")
+        .arg(&commit)
+        .arg("--message")
+        .arg("docs: a compliant commit")
+        .arg("--force")
+        .assert()
+        .success();
+
+    assert_that!(run_fun!(git log --format=%s -n 1 HEAD~1)?)
+        .is_equal_to("docs: a compliant commit".to_string());
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::Command;
+use indoc::formatdoc;
+use pretty_assertions::assert_eq;
+use sealed_test::prelude::*;
+
+#[sealed_test]
+fn list_semver_tags() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let v0_1_0 = git_commit("feat: feature")?;
+    git_tag("0.1.0")?;
+    git_commit("fix: bug fix")?;
+    git_tag("not-semver")?;
+    let v1_0_0 = git_commit("feat!: breaking change")?;
+    git_tag("1.0.0")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("tags")
+        // Assert
+        .assert()
+        .success();
+
+    let output = String::from_utf8_lossy(&output.get_output().stdout).to_string();
+
+    assert_eq!(
+        output,
+        formatdoc!(
+            "0.1.0 - {v0_1_0} - 2 commits
+            1.0.0 - {v1_0_0} - 2 commits (latest)
+            ",
+            v0_1_0 = &v0_1_0[0..7],
+            v1_0_0 = &v1_0_0[0..7],
+        )
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn list_semver_tags_as_json() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let v0_1_0 = git_commit("feat: feature")?;
+    git_tag("0.1.0")?;
+    git_commit("fix: bug fix")?;
+    let v0_1_1 = git_commit("fix: another bug fix")?;
+    git_tag("0.1.1")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("tags")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let output = String::from_utf8_lossy(&output.get_output().stdout).to_string();
+
+    assert_eq!(
+        output,
+        formatdoc!(
+            r#"[
+              {{
+                "tag": "0.1.0",
+                "id": "{v0_1_0}",
+                "commits": 1,
+                "latest": false
+              }},
+              {{
+                "tag": "0.1.1",
+                "id": "{v0_1_1}",
+                "commits": 2,
+                "latest": true
+              }}
+            ]
+            "#
+        )
+    );
+    Ok(())
+}
@@ -0,0 +1,271 @@
+use std::process::Command;
+
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use indoc::indoc;
+use predicates::prelude::predicate;
+use sealed_test::prelude::*;
+
+#[sealed_test]
+fn validate_config_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"tag_prefix = "v"
+        branch_whitelist = ["main", "release/**"]
+
+        [commit_types]
+        hotfix = { changelog_title = "Hotfixes" }
+
+        [bump]
+        initial_version = "1.0.0"
+
+        [changelog]
+        template = "remote"
+        remote = "github.com"
+        owner = "cocogitto"
+        repository = "cocogitto"
+        link_issues = true
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is valid"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_unknown_key_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"tag_prefix = "v"
+
+        [changelog]
+        templat = "remote"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("line 3: unknown field `templat`"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_reports_all_problems() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"branch_whitelist = ["[main"]
+
+        [commit_types]
+        "hot fix" = { changelog_title = "" }
+
+        [bump]
+        initial_version = "one"
+
+        [changelog]
+        template = "missing_template.tera"
+        issue_url_template = "https://tracker.example.org/issues"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "line 1: `branch_whitelist`: invalid glob pattern",
+        ))
+        .stderr(predicate::str::contains(
+            "line 4: `hot fix`: commit type must be a single word",
+        ))
+        .stderr(predicate::str::contains(
+            "line 4: `hot fix`: changelog_title must not be empty",
+        ))
+        .stderr(predicate::str::contains(
+            "line 7: `initial_version`: invalid version",
+        ))
+        .stderr(predicate::str::contains(
+            "line 10: `template`: changelog template not found",
+        ))
+        .stderr(predicate::str::contains(
+            "line 11: `issue_url_template`: must contain the `{{id}}` placeholder",
+        ))
+        .stderr(predicate::str::contains("6 problem(s) found"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_invalid_template_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write("template.tera", "{% for commit in commits %}")?;
+    let settings = indoc!(
+        r#"[changelog]
+        template = "template.tera"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "line 2: `template`: invalid template",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_title_for_unknown_type_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[changelog.titles]
+        feat = "New Features"
+        wip = "Work in progress"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "line 3: `wip`: changelog title defined for an unknown commit type",
+        ))
+        .stderr(predicate::str::contains("1 problem(s) found"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_section_merge_for_unknown_type_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[changelog.section_merge]
+        perf = "feat"
+        wip = "Internal"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "line 3: `wip`: changelog section merge defined for an unknown commit type",
+        ))
+        .stderr(predicate::str::contains("1 problem(s) found"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_unknown_keepachangelog_category_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[changelog.keepachangelog_categories]
+        docs = "Changed"
+        chore = "Maintenance"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "line 3: `chore`: unknown Keep a Changelog category `Maintenance`, \
+            expected one of Added, Changed, Deprecated, Removed, Fixed, Security",
+        ))
+        .stderr(predicate::str::contains("1 problem(s) found"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_tag_pattern_without_version_group_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"[bump]
+        tag_pattern = "^release/(.+)$"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "line 2: `tag_pattern`: missing a `version` capture group",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn validate_config_tag_format_with_tag_prefix_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"tag_prefix = "v"
+
+        [bump]
+        tag_format = "{scope}@{version}"
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("validate-config")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "`tag_format`: cannot be combined with `tag_prefix` nor `bump.tag_pattern`",
+        ));
+
+    Ok(())
+}
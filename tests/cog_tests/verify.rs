@@ -148,3 +148,626 @@ fn should_ignore_merge_commit_via_config() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn verify_with_uppercase_commit_type_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let message = "Feat: a commit message";
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg(message)
+        // Assert
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_uppercase_commit_type_and_case_sensitive_types_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[commit]\ncase_insensitive_types = false" > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("Feat: a commit message")
+        // Assert
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_case_insensitive_types() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        case_insensitive_types = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    for message in ["Feat: a commit message", "FIX(parser): a commit message"] {
+        Command::cargo_bin("cog")?
+            .arg("verify")
+            .arg(message)
+            // Assert
+            .assert()
+            .success();
+    }
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_custom_type_with_case_insensitive_types() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        case_insensitive_types = true
+
+        [commit_types]
+        hotfix = { changelog_title = \"Hotfixes\" }"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("HotFix: a commit message")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("Type: hotfix"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_required_signoff() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        require_signoff = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a commit message\n\nSigned-off-by: Jane Doe <jane@example.org>")
+        // Assert
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_without_required_signoff_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        require_signoff = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a commit message")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Signed-off-by: Name <email>"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_breaking_change_with_required_description() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        require_breaking_body = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat!: drop the v1 api\n\nBREAKING CHANGE: v1 clients must upgrade")
+        // Assert
+        .assert()
+        .success();
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat!: drop the v1 api\n\nThe v1 endpoints are removed.")
+        .assert()
+        .success();
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a regular feature")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_breaking_change_without_required_description_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        require_breaking_body = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat!: drop the v1 api")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Breaking change without a body or",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_malformed_signoff_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        require_signoff = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a commit message\n\nSigned-off-by: Jane Doe")
+        // Assert
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_gitmoji() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        gitmoji = true"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "feat: cog.toml config"
+    )?;
+
+    // Act
+    for message in [
+        ":sparkles: feat(api): a commit message",
+        "🐛 fix: a commit message",
+    ] {
+        Command::cargo_bin("cog")?
+            .arg("verify")
+            .arg(message)
+            // Assert
+            .assert()
+            .success();
+    }
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_gitmoji_fails_by_default() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg(":sparkles: feat(api): a commit message")
+        // Assert
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_empty_description_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    for message in ["feat:", "feat: ", "feat:   "] {
+        Command::cargo_bin("cog")?
+            .arg("verify")
+            .arg(message)
+            // Assert
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains("Commit description is empty"));
+    }
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_hyphenated_and_dotted_scopes() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    for message in ["feat(ci-cd): x", "feat(web.ui): x"] {
+        Command::cargo_bin("cog")?
+            .arg("verify")
+            .arg(message)
+            // Assert
+            .assert()
+            .success();
+    }
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_invalid_scope_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat(web:ui): x")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Invalid scope"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_without_space_after_separator_warns() -> Result<()> {
+    // Arrange
+    git_init()?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat:x")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("Missing space after `:`"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_scope_required_types() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        scope_required_types = [\"feat\", \"fix\"]"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a feature without scope")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "A scope is required for `feat` commits",
+        ));
+
+    for message in ["feat(api): a scoped feature", "chore: a scopeless chore"] {
+        Command::cargo_bin("cog")?
+            .arg("verify")
+            .arg(message)
+            .assert()
+            .success();
+    }
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_scope_forbidden_types() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        scope_forbidden_types = [\"chore\"]"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("chore(deps): a scoped chore")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "A scope is not allowed for `chore` commits",
+        ));
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat(api): a scoped feature")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_lower_subject_case() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        subject_case = \"lower\""
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: Add a feature")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Commit description must start with a lowercase letter",
+        ));
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: add a feature")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_sentence_subject_case() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        subject_case = \"sentence\""
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: Cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat(api): add a feature")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Commit description must start with an uppercase letter",
+        ));
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat(api): Add a feature")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_any_subject_case() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        subject_case = \"any\""
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    for message in ["feat: add a feature", "feat: Add a feature"] {
+        Command::cargo_bin("cog")?
+            .arg("verify")
+            .arg(message)
+            // Assert
+            .assert()
+            .success();
+    }
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_long_body_line() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        max_body_line_length = 20"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: add a feature\n\nshort line\nthis body line is way too long")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Commit body line 2 is 30 characters long, the maximum is 20",
+        ));
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg(
+            "feat: add a feature\n\nshort line\n\nReviewed-by: a footer longer than the body limit",
+        )
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_long_body_url_exempted() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        max_body_line_length = 20"
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("fix: a bug\n\nsee https://example.org/a/very/long/url/to/the/issue")
+        // Assert
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_long_body_line_as_warning() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        "[commit]
+        max_body_line_length = 20
+        body_line_length_severity = \"warning\""
+    );
+
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: cog.toml config"
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: add a feature\n\nthis body line is way too long")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "Commit body line 1 is 30 characters long, the maximum is 20",
+        ));
+
+    Ok(())
+}
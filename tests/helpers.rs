@@ -9,10 +9,21 @@ use speculoos::option::OptionAssertions;
 
 use cocogitto::CONFIG_PATH;
 
+/// Point the user-global cog.toml lookup at an empty directory, so tests do not pick
+/// up the developer `~/.config/cocogitto/cog.toml`
+pub fn isolate_global_config() {
+    std::env::set_var(
+        "XDG_CONFIG_HOME",
+        std::env::temp_dir().join("cocogitto-tests-config"),
+    );
+}
+
 /// - Init a repository in the current directory
 /// - Setup a local git user named Tom <toml.bombadil@themail.org>
+/// - Ignore the user-global cog.toml
 pub fn git_init() -> Result<()> {
     init_builtin_logger();
+    isolate_global_config();
     run_cmd!(
         git init;
         git config --local user.name Tom;
@@ -25,8 +36,10 @@ pub fn git_init() -> Result<()> {
 /// - Init a repository in the given path
 /// - Change the current directory to the newly created repository
 /// - Setup a local git user named Tom <toml.bombadil@themail.org>
+/// - Ignore the user-global cog.toml
 pub fn git_init_and_set_current_path(path: &str) -> Result<()> {
     init_builtin_logger();
+    isolate_global_config();
     run_cmd!(
         git init $path;
         cd $path;
@@ -1,7 +1,7 @@
 use anyhow::Result;
 
 use cmd_lib::run_cmd;
-use cocogitto::{conventional::version::VersionIncrement, CocoGitto};
+use cocogitto::{conventional::version::VersionIncrement, BumpOptions, CocoGitto};
 use indoc::indoc;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
@@ -20,7 +20,7 @@ fn bump_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
 
     // Assert
     assert_that!(result).is_ok();
@@ -38,7 +38,7 @@ fn should_fallback_to_0_0_0_when_there_is_no_tag() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
 
     // Assert
     assert_that!(result).is_ok();
@@ -58,7 +58,7 @@ fn should_fail_when_latest_tag_is_not_semver_compliant() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
     let error = result.unwrap_err().to_string();
     let error = error.as_str();
 
@@ -89,7 +89,7 @@ fn bump_with_whitelisted_branch_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
 
     // Assert
     assert_that!(result).is_ok();
@@ -114,7 +114,7 @@ fn bump_with_whitelisted_branch_fails() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
 
     // Assert
     assert_that!(result.unwrap_err().to_string()).is_equal_to(
@@ -143,7 +143,7 @@ fn bump_with_whitelisted_branch_pattern_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
 
     // Assert
     assert_that!(result).is_ok();
@@ -168,10 +168,213 @@ fn bump_with_whitelisted_branch_pattern_err() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(VersionIncrement::Auto, None, None, false);
+    let result = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default());
 
     // Assert
     assert_that!(result).is_err();
 
     Ok(())
 }
+
+#[sealed_test]
+fn bump_returns_release_summary() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: first commit")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: add a feature commit")?;
+    git_commit("fix: fix a bug")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let release = cocogitto.create_version(VersionIncrement::Auto, BumpOptions::default())?;
+
+    // Assert
+    assert_that!(release.version).is_equal_to("1.1.0".to_string());
+    assert_that!(release.tag).is_equal_to("1.1.0".to_string());
+    assert_that!(release.previous_version).is_equal_to(Some("1.0.0".to_string()));
+    assert_that!(release.commits).is_equal_to(2);
+    let changelog = release.changelog.unwrap();
+    assert_that!(changelog).contains("## 1.1.0");
+    assert_that!(changelog).contains("- add a feature commit");
+    assert_that!(changelog).contains("- fix a bug");
+    assert_latest_tag("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_dry_run_returns_release_summary() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: first commit")?;
+    git_commit("feat: add a feature commit")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let release = cocogitto.create_version(
+        VersionIncrement::Auto,
+        BumpOptions {
+            dry_run: true,
+            ..Default::default()
+        },
+    )?;
+
+    // Assert
+    assert_that!(release.version).is_equal_to("0.1.0".to_string());
+    assert_that!(release.previous_version).is_none();
+    assert_that!(release.commits).is_equal_to(2);
+    assert_that!(release.changelog).is_none();
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+fn next_version_from_1_2_3(increment: VersionIncrement) -> Result<String> {
+    git_init()?;
+    git_commit("chore: first commit")?;
+    git_tag("1.2.3")?;
+    git_commit("feat: add a feature commit")?;
+    let cocogitto = CocoGitto::get()?;
+
+    let version = cocogitto.next_version(increment)?;
+
+    assert_tag_does_not_exist(&version.to_string())?;
+    Ok(version.to_string())
+}
+
+#[sealed_test]
+fn next_version_major() -> Result<()> {
+    // Act
+    let version = next_version_from_1_2_3(VersionIncrement::Major)?;
+
+    // Assert
+    assert_that!(version).is_equal_to("2.0.0".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_minor() -> Result<()> {
+    // Act
+    let version = next_version_from_1_2_3(VersionIncrement::Minor)?;
+
+    // Assert
+    assert_that!(version).is_equal_to("1.3.0".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_patch() -> Result<()> {
+    // Act
+    let version = next_version_from_1_2_3(VersionIncrement::Patch)?;
+
+    // Assert
+    assert_that!(version).is_equal_to("1.2.4".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_auto() -> Result<()> {
+    // Act
+    let version = next_version_from_1_2_3(VersionIncrement::Auto)?;
+
+    // Assert
+    assert_that!(version).is_equal_to("1.3.0".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_manual() -> Result<()> {
+    // Act
+    let version = next_version_from_1_2_3(VersionIncrement::Manual("1.5.0".to_string()))?;
+
+    // Assert
+    assert_that!(version).is_equal_to("1.5.0".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_with_tag_prefix() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "tag_prefix = \"v\"" > cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: first commit")?;
+    git_tag("v1.2.3")?;
+    git_commit("fix: fix a bug")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let version = cocogitto.next_version(VersionIncrement::Auto)?;
+
+    // Assert
+    assert_that!(version.to_string()).is_equal_to("1.2.4".to_string());
+    assert_tag_does_not_exist("v1.2.4")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_lower_than_current_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: first commit")?;
+    git_tag("1.2.3")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let result = cocogitto.next_version(VersionIncrement::Manual("1.0.0".to_string()));
+
+    // Assert
+    assert_that!(result).is_err();
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_with_release_directory_tag_pattern() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]" > cog.toml;
+        echo "tag_pattern = \"^release/(?P<version>.+)$\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: first commit")?;
+    git_tag("release/1.2.3")?;
+    git_tag("9.0.0")?;
+    git_commit("feat: add a feature")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let version = cocogitto.next_version(VersionIncrement::Auto)?;
+
+    // Assert
+    assert_that!(version.to_string()).is_equal_to("1.3.0".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn next_version_with_suffixed_tag_pattern() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        echo "[bump]" > cog.toml;
+        echo "tag_pattern = \"^v(?P<version>\\\\d+\\\\.\\\\d+\\\\.\\\\d+)-company$\"" >> cog.toml;
+        git add .;
+    )?;
+    git_commit("chore: first commit")?;
+    git_tag("v1.2.3-company")?;
+    git_commit("fix: fix a bug")?;
+    git_tag("v1.10.0-company")?;
+    git_tag("v2.0.0-other")?;
+    git_commit("fix: fix another bug")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let version = cocogitto.next_version(VersionIncrement::Auto)?;
+
+    // Assert
+    assert_that!(version.to_string()).is_equal_to("1.10.1".to_string());
+    Ok(())
+}
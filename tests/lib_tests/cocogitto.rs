@@ -1,9 +1,13 @@
 use crate::helpers::*;
 
 use anyhow::Result;
+use cmd_lib::run_cmd;
+use cocogitto::conventional::changelog::template::Template;
+use cocogitto::git::revspec::RevspecPattern;
 use cocogitto::CocoGitto;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
+use std::path::Path;
 
 #[sealed_test]
 fn open_repo_ok() -> Result<()> {
@@ -32,6 +36,132 @@ fn open_repo_err() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn open_repo_from_path_ok() -> Result<()> {
+    // Arrange
+    run_cmd!(
+        git init -q repo;
+        git -C repo commit --allow-empty -q -m "feat: a valid commit";
+    )?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), None)?;
+
+    // Assert
+    assert_that!(cocogitto.check(false, false, false)).is_ok();
+    assert_that!(cocogitto.get_repo_tag_name()).is_equal_to(Some("repo on master".to_string()));
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_with_malformed_config_err() -> Result<()> {
+    // Arrange
+    run_cmd!(git init -q repo;)?;
+    std::fs::write("repo/cog.toml", "unknown_field = true")?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), None);
+
+    // Assert
+    assert_that!(cocogitto).is_err();
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_with_invalid_tag_pattern_err() -> Result<()> {
+    // Arrange
+    run_cmd!(git init -q repo;)?;
+    std::fs::write(
+        "repo/cog.toml",
+        "[bump]\ntag_pattern = \"release/(?P<version>\"",
+    )?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), None);
+
+    // Assert
+    let err = cocogitto.err().map(|err| err.to_string());
+    assert_that!(err)
+        .is_some()
+        .contains("invalid `bump.tag_pattern` regex");
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_with_invalid_ticket_prefix_pattern_err() -> Result<()> {
+    // Arrange
+    run_cmd!(git init -q repo;)?;
+    std::fs::write(
+        "repo/cog.toml",
+        "[commit]\nticket_prefix_pattern = \"([A-Z]+-\"",
+    )?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), None);
+
+    // Assert
+    let err = cocogitto.err().map(|err| err.to_string());
+    assert_that!(err)
+        .is_some()
+        .contains("invalid `commit.ticket_prefix_pattern` regex");
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_with_config_ok() -> Result<()> {
+    // Arrange
+    run_cmd!(git init -q repo;)?;
+    std::fs::write("config.toml", "ignore_merge_commits = false")?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), Some(Path::new("config.toml")));
+
+    // Assert
+    assert_that!(cocogitto).is_ok();
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_with_missing_config_err() -> Result<()> {
+    // Arrange
+    run_cmd!(git init -q repo;)?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), Some(Path::new("config.toml")));
+
+    // Assert
+    let err = cocogitto.err().map(|err| err.to_string());
+    assert_that!(err).is_some().contains("not found");
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_with_other_settings_err() -> Result<()> {
+    // Arrange
+    run_cmd!(git init -q repo;)?;
+    std::fs::write("repo/cog.toml", "tag_prefix = \"v\"")?;
+
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("repo"), None);
+
+    // Assert
+    let err = cocogitto.err().map(|err| err.to_string());
+    assert_that!(err)
+        .is_some()
+        .contains("differ from the ones of the current directory");
+    Ok(())
+}
+
+#[sealed_test]
+fn open_repo_from_path_err() -> Result<()> {
+    // Act
+    let cocogitto = CocoGitto::from_path(Path::new("not_a_repo"), None);
+
+    // Assert
+    assert_that!(cocogitto).is_err();
+    Ok(())
+}
+
 #[sealed_test]
 fn check_commit_history_ok() -> Result<()> {
     // Arrange
@@ -41,7 +171,7 @@ fn check_commit_history_ok() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false);
+    let check = cocogitto.check(false, false, false);
 
     // Assert
     assert_that!(check).is_ok();
@@ -57,7 +187,7 @@ fn check_commit_history_err_with_merge_commit() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false);
+    let check = cocogitto.check(false, false, false);
 
     // Assert
     assert_that!(check).is_err();
@@ -73,7 +203,7 @@ fn check_commit_history_ok_with_merge_commit_ignored() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, true);
+    let check = cocogitto.check(false, true, false);
 
     // Assert
     assert_that!(check).is_ok();
@@ -90,7 +220,7 @@ fn check_commit_history_err() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false);
+    let check = cocogitto.check(false, false, false);
 
     // Assert
     assert_that!(check).is_err();
@@ -109,7 +239,7 @@ fn check_commit_ok_from_latest_tag() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(true, false);
+    let check = cocogitto.check(true, false, false);
 
     // Assert
     assert_that!(check).is_ok();
@@ -127,13 +257,61 @@ fn check_commit_err_from_latest_tag() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(true, false);
+    let check = cocogitto.check(true, false, false);
 
     // Assert
     assert_that!(check).is_err();
     Ok(())
 }
 
+#[sealed_test]
+fn check_range_with_mixed_validity_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("0.1.0")?;
+    let feature = git_commit("feat: a feature")?;
+    let errored = git_commit("not a conventional commit")?;
+    let fix = git_commit("fix: a fix")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let results = cocogitto.check_range(Some("0.1.0"), None, false)?;
+
+    // Assert
+    let results: Vec<(&str, bool)> = results
+        .iter()
+        .map(|result| (result.oid.as_str(), result.passed()))
+        .collect();
+    assert_that!(results).is_equal_to(vec![
+        (fix.as_str(), true),
+        (errored.as_str(), false),
+        (feature.as_str(), true),
+    ]);
+    Ok(())
+}
+
+#[sealed_test]
+fn check_range_reports_violation_reason() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let errored = git_commit("toto: unknown type")?;
+    git_commit("feat: a feature")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let results = cocogitto.check_range(None, Some(&errored), false)?;
+
+    // Assert
+    assert_that!(results).has_length(1);
+    let error = results[0].error.as_ref().map(ToString::to_string);
+    assert_that!(error)
+        .is_some()
+        .contains("Commit type `toto` not allowed");
+    Ok(())
+}
+
 #[sealed_test]
 fn long_commit_summary_does_not_panic() -> Result<()> {
     git_init()?;
@@ -142,10 +320,34 @@ fn long_commit_summary_does_not_panic() -> Result<()> {
 
     let cocogitto = CocoGitto::get()?;
     git_add("Hello", "file")?;
-    cocogitto.conventional_commit("feat", None, message, None, None, false, false)?;
+    cocogitto.conventional_commit("feat", None, message, None, None, false, false, false)?;
 
-    let check = cocogitto.check(false, false);
+    let check = cocogitto.check(false, false, false);
 
     assert_that!(check.is_ok());
     Ok(())
 }
+
+#[sealed_test]
+fn get_latest_changelog_renders_unreleased_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: released feature")?;
+    git_tag("0.1.0")?;
+    git_commit("feat: unreleased feature")?;
+    git_commit("fix: unreleased fix")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let changelog = cocogitto.get_latest_changelog()?;
+
+    // Assert
+    let expected = cocogitto
+        .get_changelog(RevspecPattern::from("0.1.0..HEAD"), false)?
+        .into_markdown(Template::default())?;
+    assert_that!(changelog).is_equal_to(expected);
+    assert_that!(changelog).contains("unreleased feature");
+    assert_that!(changelog).does_not_contain("- released feature");
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use cocogitto::log::filter::{CommitFilter, CommitFilters};
+use cocogitto::log::order::LogOrder;
 use cocogitto::CocoGitto;
 
 use crate::helpers::*;
@@ -18,7 +19,7 @@ fn get_unfiltered_logs() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let logs = cocogitto.get_log(filters)?;
+    let logs = cocogitto.get_log(filters, LogOrder::default(), false, true)?;
 
     // Assert
     assert_that!(logs).contains("I am afraid I can't do that Dave");
@@ -39,7 +40,7 @@ fn get_log_with_no_errors() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let logs = cocogitto.get_log(filters)?;
+    let logs = cocogitto.get_log(filters, LogOrder::default(), false, true)?;
 
     // Assert
     assert_that!(logs).does_not_contain("Errored commit:");
@@ -48,3 +49,158 @@ fn get_log_with_no_errors() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn get_log_with_full_hash() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let oid = git_commit("feat: a commit")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let short_logs = cocogitto.get_log(CommitFilters(vec![]), LogOrder::default(), false, true)?;
+    let full_logs = cocogitto.get_log(CommitFilters(vec![]), LogOrder::default(), true, true)?;
+
+    // Assert
+    assert_that!(short_logs).contains(&oid[0..6]);
+    assert_that!(short_logs).does_not_contain(oid.as_str());
+    assert_that!(full_logs).contains(oid.as_str());
+
+    Ok(())
+}
+
+#[sealed_test]
+fn count_log_with_filters() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: a commit")?;
+    git_commit("feat(api): another commit")?;
+    git_commit("fix: a fix")?;
+    git_commit("I am afraid I can't do that Dave")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let all = cocogitto.count_log(CommitFilters(vec![]))?;
+    let no_error = cocogitto.count_log(CommitFilters(vec![CommitFilter::NoError]))?;
+    let features = cocogitto.count_log(CommitFilters(vec![
+        CommitFilter::Type("feat".into()),
+        CommitFilter::NoError,
+    ]))?;
+
+    // Assert
+    assert_that!(all).is_equal_to(4);
+    assert_that!(no_error).is_equal_to(3);
+    assert_that!(features).is_equal_to(2);
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_with_configured_breaking_change_keyword() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::write(
+        "cog.toml",
+        "[commit]\nbreaking_change_keywords = [\"CAMBIO IMPORTANTE\"]",
+    )?;
+    git_commit("feat: a commit")?;
+    git_commit("feat: new api\n\nCAMBIO IMPORTANTE: the old api is gone")?;
+    git_commit("fix!: a breaking fix")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let breaking_changes = cocogitto.count_log(CommitFilters(vec![
+        CommitFilter::BreakingChange,
+        CommitFilter::NoError,
+    ]))?;
+
+    // Assert
+    assert_that!(breaking_changes).is_equal_to(2);
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_with_path_filter() -> Result<()> {
+    // Arrange
+    git_init()?;
+    std::fs::create_dir_all("packages/api")?;
+    std::fs::create_dir_all("packages/web")?;
+    git_add("api", "packages/api/lib.rs")?;
+    git_commit("feat(api): add the api package")?;
+    git_add("web", "packages/web/index.js")?;
+    git_commit("feat(web): add the web package")?;
+    git_add("api v2", "packages/api/lib.rs")?;
+    git_commit("fix: fix the api")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(
+        CommitFilters(vec![CommitFilter::Path("./packages/api/".into())]),
+        LogOrder::default(),
+        false,
+        true,
+    )?;
+
+    // Assert
+    assert_that!(logs).contains("add the api package");
+    assert_that!(logs).contains("fix the api");
+    assert_that!(logs).does_not_contain("add the web package");
+    Ok(())
+}
+
+#[sealed_test]
+fn write_release_history_as_json() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: first feature")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: second feature")?;
+    git_commit("fix: a bug fix")?;
+    git_tag("1.1.0")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let mut output = vec![];
+    cocogitto.write_release_history(&mut output)?;
+
+    // Assert
+    let releases: serde_json::Value = serde_json::from_slice(&output)?;
+    let releases = releases.as_array().expect("an array of releases");
+    assert_that!(releases).has_length(2);
+
+    let latest = &releases[0];
+    assert_that!(latest["version"]["tag"]).is_equal_to(&serde_json::json!("1.1.0"));
+    assert_that!(latest["date"].is_string()).is_true();
+    assert_that!(latest["commits"]["Features"][0]["summary"])
+        .is_equal_to(&serde_json::json!("second feature"));
+    assert_that!(latest["commits"]["Bug Fixes"][0]["summary"])
+        .is_equal_to(&serde_json::json!("a bug fix"));
+
+    let first = &releases[1];
+    assert_that!(first["version"]["tag"]).is_equal_to(&serde_json::json!("1.0.0"));
+    assert_that!(first["commits"]["Features"][0]["summary"])
+        .is_equal_to(&serde_json::json!("first feature"));
+    assert_that!(first["commits"].get("Bug Fixes")).is_none();
+    Ok(())
+}
+
+#[sealed_test]
+fn get_markdown_log_fences_errored_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: a commit")?;
+    git_commit("I am afraid I can't do that Dave")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs =
+        cocogitto.get_markdown_log(CommitFilters(vec![]), LogOrder::default(), false, true)?;
+
+    // Assert
+    assert_that!(logs).starts_with("```text\n");
+    assert_that!(logs).contains("I am afraid I can't do that Dave");
+    assert_that!(logs).contains("```\n- **feat:** a commit - (`");
+    Ok(())
+}